@@ -1,4 +1,7 @@
 fn main() {
+    // Baked into the `dirty` label of mariadb_exporter_build_info
+    println!("cargo:rerun-if-env-changed=MARIADB_EXPORTER_BUILD_DIRTY");
+
     if let Err(e) = built::write_built_file() {
         eprintln!("Failed to acquire build-time information: {e}");
         std::process::exit(1);
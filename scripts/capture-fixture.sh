@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Capture a server's status into a replay fixture (tests/fixtures/<name>), so collectors
+# can be tested without a database: cargo test --test replay
+set -euo pipefail
+
+NAME="${1:?usage: capture-fixture.sh <name> [host] [port] [user] [password]}"
+HOST="${2:-127.0.0.1}"
+PORT="${3:-3306}"
+USER="${4:-root}"
+PASS="${5:-root}"
+
+DIR="$(dirname "$0")/../tests/fixtures/${NAME}"
+mkdir -p "$DIR"
+
+query() {
+    # --raw keeps the newlines of SHOW ENGINE INNODB STATUS unescaped
+    mariadb -h "$HOST" -P "$PORT" -u "$USER" -p"$PASS" --batch --skip-column-names --raw -e "$1"
+}
+
+echo "📸 Capturing ${HOST}:${PORT} into ${DIR}..."
+
+query "SELECT VARIABLE_NAME, VARIABLE_VALUE FROM information_schema.global_status ORDER BY VARIABLE_NAME" \
+    >"$DIR/global_status.tsv"
+query "SELECT VARIABLE_NAME, VARIABLE_VALUE FROM information_schema.global_variables ORDER BY VARIABLE_NAME" \
+    >"$DIR/global_variables.tsv"
+query "SHOW ENGINE INNODB STATUS" | cut -f3- >"$DIR/innodb_status.txt"
+
+echo "✅ Review the files for hostnames or other private values before committing them"
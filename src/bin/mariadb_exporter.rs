@@ -9,6 +9,8 @@ async fn main() -> Result<()> {
 
     match action {
         Action::Run { .. } => actions::run::handle(action).await?,
+        Action::Validate { .. } => actions::validate::handle(action).await?,
+        Action::ListMetrics => actions::list_metrics::handle(&action)?,
     }
 
     Ok(())
@@ -0,0 +1,39 @@
+use crate::cli::actions::Action;
+use crate::collectors::catalog::metric_catalog;
+use anyhow::{Result, anyhow};
+
+/// Handle the list-metrics action
+///
+/// # Errors
+///
+/// Returns an error if a collector fails to register or the catalog can't be serialized
+pub fn handle(action: &Action) -> Result<()> {
+    match action {
+        Action::ListMetrics => {
+            println!("{}", serde_json::to_string_pretty(&metric_catalog()?)?);
+        }
+        Action::Run { .. } | Action::Validate { .. } => {
+            return Err(anyhow!("not a list-metrics action"));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_list_metrics() {
+        assert!(handle(&Action::ListMetrics).is_ok());
+        assert!(
+            handle(&Action::Validate {
+                dsns: Vec::new(),
+                collectors: Vec::new(),
+                pool: crate::exporter::PoolConfig::default(),
+            })
+            .is_err()
+        );
+    }
+}
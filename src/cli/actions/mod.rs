@@ -1,13 +1,26 @@
+pub mod list_metrics;
 pub mod run;
+pub mod validate;
 
+use crate::exporter::{PoolConfig, WebConfig};
 use secrecy::SecretString;
+use std::{collections::BTreeMap, path::PathBuf};
 
 #[derive(Debug)]
 pub enum Action {
     Run {
-        port: u16,
-        listen: Option<String>,
-        dsn: SecretString,
+        web: WebConfig,
+        dsns: Vec<SecretString>,
+        dsn_file: Option<PathBuf>,
+        password_file: Option<PathBuf>,
         collectors: Vec<String>,
+        external_labels: BTreeMap<String, String>,
+        pool: PoolConfig,
     },
+    Validate {
+        dsns: Vec<SecretString>,
+        collectors: Vec<String>,
+        pool: PoolConfig,
+    },
+    ListMetrics,
 }
@@ -1,6 +1,6 @@
 use crate::cli::actions::Action;
 use crate::exporter::new;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 
 /// Handle the run action
 ///
@@ -10,12 +10,27 @@ use anyhow::Result;
 pub async fn handle(action: Action) -> Result<()> {
     match action {
         Action::Run {
-            port,
-            listen,
-            dsn,
+            web,
+            dsns,
+            dsn_file,
+            password_file,
             collectors,
+            external_labels,
+            pool,
         } => {
-            new(port, listen, dsn, collectors).await?;
+            new(
+                web,
+                dsns,
+                collectors,
+                external_labels,
+                pool,
+                dsn_file,
+                password_file,
+            )
+            .await?;
+        }
+        Action::Validate { .. } | Action::ListMetrics => {
+            return Err(anyhow!("not a run action"));
         }
     }
 
@@ -25,15 +40,23 @@ pub async fn handle(action: Action) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exporter::{PoolConfig, WebConfig};
     use secrecy::SecretString;
+    use std::collections::BTreeMap;
 
     #[tokio::test]
     async fn test_handle_action_signature() {
         let action = Action::Run {
-            port: 9999,
-            listen: None,
-            dsn: SecretString::new("invalid-dsn".into()),
+            web: WebConfig {
+                port: 9999,
+                ..WebConfig::default()
+            },
+            dsns: vec![SecretString::new("invalid-dsn".into())],
+            dsn_file: None,
+            password_file: None,
             collectors: vec!["default".to_string()],
+            external_labels: BTreeMap::new(),
+            pool: PoolConfig::default(),
         };
 
         let result = handle(action).await;
@@ -44,42 +67,64 @@ mod tests {
     #[test]
     fn test_action_creation() {
         let action = Action::Run {
-            port: 9306,
-            listen: Some("127.0.0.1".to_string()),
-            dsn: SecretString::new("mysql://root@localhost:3306/mysql".into()),
+            web: WebConfig {
+                listen: Some("127.0.0.1".to_string()),
+                ..WebConfig::default()
+            },
+            dsns: vec![SecretString::new(
+                "mysql://root@localhost:3306/mysql".into(),
+            )],
+            dsn_file: None,
+            password_file: None,
             collectors: vec!["default".to_string(), "exporter".to_string()],
+            external_labels: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+            pool: PoolConfig::default(),
         };
 
-        match action {
-            Action::Run {
-                port,
-                listen,
-                dsn: _,
-                collectors,
-            } => {
-                assert_eq!(port, 9306);
-                assert_eq!(listen, Some("127.0.0.1".to_string()));
-                assert_eq!(collectors.len(), 2);
-                assert!(collectors.contains(&"default".to_string()));
-                assert!(collectors.contains(&"exporter".to_string()));
-            }
-        }
+        let Action::Run {
+            web,
+            dsns: _,
+            dsn_file: _,
+            password_file: _,
+            collectors,
+            external_labels,
+            pool,
+        } = action
+        else {
+            unreachable!("expected a run action");
+        };
+
+        assert_eq!(web.port, 9306);
+        assert_eq!(web.listen, Some("127.0.0.1".to_string()));
+        assert_eq!(web.telemetry_path, "/metrics");
+        assert!(!web.access.is_enabled());
+        assert_eq!(collectors.len(), 2);
+        assert!(collectors.contains(&"default".to_string()));
+        assert!(collectors.contains(&"exporter".to_string()));
+        assert_eq!(external_labels.get("env").map(String::as_str), Some("prod"));
+        assert_eq!(pool, PoolConfig::default());
     }
 
     #[test]
     fn test_action_with_empty_collectors() {
         // Test that Action can be created with empty collectors list
         let action = Action::Run {
-            port: 8080,
-            listen: None,
-            dsn: SecretString::new("mysql://localhost:3306/mysql".into()),
+            web: WebConfig {
+                port: 8080,
+                ..WebConfig::default()
+            },
+            dsns: vec![SecretString::new("mysql://localhost:3306/mysql".into())],
+            dsn_file: None,
+            password_file: None,
             collectors: vec![],
+            external_labels: BTreeMap::new(),
+            pool: PoolConfig::default(),
         };
 
-        match action {
-            Action::Run { collectors, .. } => {
-                assert_eq!(collectors.len(), 0, "Should allow empty collectors list");
-            }
-        }
+        let Action::Run { collectors, .. } = action else {
+            unreachable!("expected a run action");
+        };
+
+        assert_eq!(collectors.len(), 0, "Should allow empty collectors list");
     }
 }
@@ -0,0 +1,51 @@
+use crate::cli::actions::Action;
+use crate::exporter::validate;
+use anyhow::{Result, anyhow};
+
+/// Handle the validate action
+///
+/// # Errors
+///
+/// Returns an error if a DSN can't be reached or a collector fails to register
+pub async fn handle(action: Action) -> Result<()> {
+    match action {
+        Action::Validate {
+            dsns,
+            collectors,
+            pool,
+        } => {
+            for result in validate(&dsns, &collectors, pool).await? {
+                println!(
+                    "{}: OK, {} metrics from {} collectors",
+                    result.instance, result.metrics, result.collectors
+                );
+            }
+        }
+        Action::Run { .. } | Action::ListMetrics => {
+            return Err(anyhow!("not a validate action"));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exporter::PoolConfig;
+    use secrecy::SecretString;
+
+    #[tokio::test]
+    async fn test_handle_invalid_dsn() {
+        let action = Action::Validate {
+            dsns: vec![SecretString::new("invalid-dsn".into())],
+            collectors: vec!["default".to_string()],
+            pool: PoolConfig::default(),
+        };
+
+        assert!(
+            handle(action).await.is_err(),
+            "Should fail with an invalid DSN"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::collectors::{COLLECTOR_NAMES, Collector, all_factories};
+use crate::collectors::{COLLECTOR_NAMES, Collector, all_factories, util::DEFAULT_METRIC_PREFIX};
 use clap::{Arg, Command};
 
 pub fn add_collectors_args(mut cmd: Command) -> Command {
@@ -13,6 +13,13 @@ pub fn add_collectors_args(mut cmd: Command) -> Command {
         let enable_flag: &'static str = Box::leak(format!("collector.{name}").into_boxed_str());
         let disable_flag: &'static str = Box::leak(format!("no-collector.{name}").into_boxed_str());
 
+        // MARIADB_EXPORTER_COLLECTOR_<NAME> / MARIADB_EXPORTER_NO_COLLECTOR_<NAME>
+        let upper = name.to_ascii_uppercase();
+        let enable_env: &'static str =
+            Box::leak(format!("MARIADB_EXPORTER_COLLECTOR_{upper}").into_boxed_str());
+        let disable_env: &'static str =
+            Box::leak(format!("MARIADB_EXPORTER_NO_COLLECTOR_{upper}").into_boxed_str());
+
         let default_indicator = if default_enabled {
             " [default: enabled]"
         } else {
@@ -28,6 +35,7 @@ pub fn add_collectors_args(mut cmd: Command) -> Command {
                 Arg::new(enable_flag)
                     .long(enable_flag)
                     .help(enable_help)
+                    .env(enable_env)
                     .action(clap::ArgAction::SetTrue)
                     .default_value(if default_enabled { "true" } else { "false" }),
             )
@@ -35,11 +43,105 @@ pub fn add_collectors_args(mut cmd: Command) -> Command {
                 Arg::new(disable_flag)
                     .long(disable_flag)
                     .help(disable_help)
+                    .env(disable_env)
                     .action(clap::ArgAction::SetTrue)
                     .overrides_with(enable_flag),
             );
     }
-    cmd
+
+    cmd.arg(
+        Arg::new("slow-collector-threshold")
+            .long("slow-collector-threshold")
+            .help("Warn when a collector takes longer than this many seconds")
+            .default_value("1")
+            .env("MARIADB_EXPORTER_SLOW_COLLECTOR_THRESHOLD")
+            .value_name("SECONDS")
+            .value_parser(clap::value_parser!(f64)),
+    )
+    .arg(
+        Arg::new("no-status.replication")
+            .long("no-status.replication")
+            .help("Skip the default collector's SHOW SLAVE STATUS query (standalone servers)")
+            .env("MARIADB_EXPORTER_NO_STATUS_REPLICATION")
+            .action(clap::ArgAction::SetTrue),
+    )
+    .arg(
+        Arg::new("userstat.split-host")
+            .long("userstat.split-host")
+            .help("Split USER_STATISTICS users like app@10.0.0.% into user and host labels")
+            .env("MARIADB_EXPORTER_USERSTAT_SPLIT_HOST")
+            .action(clap::ArgAction::SetTrue),
+    )
+    .arg(
+        Arg::new("max-series-per-collector")
+            .long("max-series-per-collector")
+            .help("Row limit for top-N collector queries (statements, schema, file_io, table_io); defaults to each collector's own limit")
+            .env("MARIADB_EXPORTER_MAX_SERIES_PER_COLLECTOR")
+            .value_name("ROWS")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+    )
+    .arg(
+        Arg::new("metric-prefix")
+            .long("metric-prefix")
+            .help("Prefix for every metric name, replacing mariadb_")
+            .default_value(DEFAULT_METRIC_PREFIX)
+            .env("MARIADB_EXPORTER_METRIC_PREFIX")
+            .value_name("PREFIX")
+            .value_parser(parse_metric_prefix),
+    )
+    .arg(
+        Arg::new("datadir")
+            .long("datadir")
+            .help("MariaDB data directory, for the host_disk collector (exporter on the database host)")
+            .env("MARIADB_EXPORTER_DATADIR")
+            .value_name("PATH")
+            .value_parser(clap::value_parser!(std::path::PathBuf)),
+    )
+    .arg(
+        Arg::new("external-label")
+            .long("external-label")
+            .help("Label for the mariadb_exporter_target_info metric, e.g. env=prod (repeatable)")
+            .env("MARIADB_EXPORTER_EXTERNAL_LABELS")
+            .value_name("KEY=VALUE")
+            .value_delimiter(',')
+            .action(clap::ArgAction::Append)
+            .value_parser(parse_external_label),
+    )
+}
+
+/// A prefix must itself start a valid Prometheus metric name: `[a-zA-Z_:][a-zA-Z0-9_:]*`.
+fn parse_metric_prefix(value: &str) -> Result<String, String> {
+    let mut chars = value.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == ':')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':');
+
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(format!("invalid metric prefix: '{value}'"))
+    }
+}
+
+/// A `key=value` pair whose key is a Prometheus label name (`[a-zA-Z_][a-zA-Z0-9_]*`), not
+/// reserved (`__` prefix) and not `instance`, which multi-target mode sets itself.
+fn parse_external_label(value: &str) -> Result<(String, String), String> {
+    let Some((key, label_value)) = value.split_once('=') else {
+        return Err(format!("expected KEY=VALUE, got '{value}'"));
+    };
+
+    let mut chars = key.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !valid || key.starts_with("__") || key == "instance" {
+        return Err(format!("invalid label name: '{key}'"));
+    }
+
+    Ok((key.to_string(), label_value.to_string()))
 }
 
 #[cfg(test)]
@@ -96,6 +198,129 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_slow_collector_threshold() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_SLOW_COLLECTOR_THRESHOLD",
+            None::<String>,
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert_eq!(
+                    matches.get_one::<f64>("slow-collector-threshold").copied(),
+                    Some(1.0)
+                );
+
+                let matches = commands::new().get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--slow-collector-threshold",
+                    "0.25",
+                ]);
+                assert_eq!(
+                    matches.get_one::<f64>("slow-collector-threshold").copied(),
+                    Some(0.25)
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_max_series_per_collector() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_MAX_SERIES_PER_COLLECTOR",
+            None::<String>,
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert_eq!(matches.get_one::<u64>("max-series-per-collector"), None);
+
+                let matches = commands::new().get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--max-series-per-collector",
+                    "50",
+                ]);
+                assert_eq!(
+                    matches.get_one::<u64>("max-series-per-collector").copied(),
+                    Some(50)
+                );
+
+                assert!(
+                    commands::new()
+                        .try_get_matches_from(vec![
+                            "mariadb_exporter",
+                            "--max-series-per-collector",
+                            "0",
+                        ])
+                        .is_err()
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_metric_prefix() {
+        temp_env::with_var("MARIADB_EXPORTER_METRIC_PREFIX", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(
+                matches
+                    .get_one::<String>("metric-prefix")
+                    .map(String::as_str),
+                Some("mariadb_")
+            );
+
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--metric-prefix",
+                "acme_db_",
+            ]);
+            assert_eq!(
+                matches
+                    .get_one::<String>("metric-prefix")
+                    .map(String::as_str),
+                Some("acme_db_")
+            );
+
+            for invalid in ["", "1db_", "acme-db_"] {
+                assert!(
+                    commands::new()
+                        .try_get_matches_from(vec!["mariadb_exporter", "--metric-prefix", invalid])
+                        .is_err(),
+                    "'{invalid}' should be rejected"
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_no_status_replication_flag() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_NO_STATUS_REPLICATION",
+            None::<String>,
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert!(!matches.get_flag("no-status.replication"));
+
+                let matches = commands::new()
+                    .get_matches_from(vec!["mariadb_exporter", "--no-status.replication"]);
+                assert!(matches.get_flag("no-status.replication"));
+            },
+        );
+    }
+
+    #[test]
+    fn test_userstat_split_host_flag() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_USERSTAT_SPLIT_HOST",
+            None::<String>,
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert!(!matches.get_flag("userstat.split-host"));
+
+                let matches = commands::new()
+                    .get_matches_from(vec!["mariadb_exporter", "--userstat.split-host"]);
+                assert!(matches.get_flag("userstat.split-host"));
+            },
+        );
+    }
+
     #[test]
     fn test_disable_flag_overrides_enable_flag() {
         let cmd = commands::new();
@@ -187,6 +412,88 @@ mod tests {
         assert!(enabled.contains(&"default".to_string()));
     }
 
+    #[test]
+    fn test_datadir() {
+        temp_env::with_var("MARIADB_EXPORTER_DATADIR", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert!(matches.get_one::<std::path::PathBuf>("datadir").is_none());
+
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--datadir",
+                "/var/lib/mysql",
+            ]);
+            assert_eq!(
+                matches.get_one::<std::path::PathBuf>("datadir"),
+                Some(&std::path::PathBuf::from("/var/lib/mysql"))
+            );
+        });
+    }
+
+    #[test]
+    fn test_external_label() {
+        temp_env::with_var("MARIADB_EXPORTER_EXTERNAL_LABELS", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert!(
+                matches
+                    .get_many::<(String, String)>("external-label")
+                    .is_none()
+            );
+
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--external-label",
+                "env=prod",
+                "--external-label",
+                "region=us-east-1,team=",
+            ]);
+            let labels: Vec<(String, String)> = matches
+                .get_many::<(String, String)>("external-label")
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect();
+            assert_eq!(
+                labels,
+                vec![
+                    ("env".to_string(), "prod".to_string()),
+                    ("region".to_string(), "us-east-1".to_string()),
+                    ("team".to_string(), String::new()),
+                ]
+            );
+
+            for invalid in [
+                "env",
+                "=prod",
+                "1env=prod",
+                "env-name=prod",
+                "__name__=x",
+                "instance=db1",
+            ] {
+                assert!(
+                    commands::new()
+                        .try_get_matches_from(vec!["mariadb_exporter", "--external-label", invalid])
+                        .is_err(),
+                    "'{invalid}' should be rejected"
+                );
+            }
+        });
+
+        temp_env::with_var(
+            "MARIADB_EXPORTER_EXTERNAL_LABELS",
+            Some("env=staging,region=eu"),
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert_eq!(
+                    matches
+                        .get_many::<(String, String)>("external-label")
+                        .map(Iterator::count),
+                    Some(2)
+                );
+            },
+        );
+    }
+
     #[test]
     fn test_enable_disabled_by_default_collector() {
         let cmd = commands::new();
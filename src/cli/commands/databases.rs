@@ -0,0 +1,135 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn add_database_args(cmd: Command) -> Command {
+    cmd.arg(
+        Arg::new("exclude-databases")
+            .long("exclude-databases")
+            .help("Comma-separated list of databases to exclude (exact/case-sensitive)")
+            .env("MARIADB_EXPORTER_EXCLUDE_DATABASES")
+            .value_name("information_schema,performance_schema,...")
+            .value_delimiter(',') // split CLI and env values by comma
+            .action(ArgAction::Append), // allow repeated flags if desired
+    )
+    .arg(
+        Arg::new("include-databases")
+            .long("include-databases")
+            .help(
+                "Comma-separated list of databases to collect (exact/case-sensitive, default: all)",
+            )
+            .env("MARIADB_EXPORTER_INCLUDE_DATABASES")
+            .value_name("app1,app2,...")
+            .value_delimiter(',')
+            .action(ArgAction::Append),
+    )
+    .arg(
+        Arg::new("statements.schemas")
+            .long("statements.schemas")
+            .help("Comma-separated list of schemas the statements collector reports top digests for (default: all, including statements without a schema)")
+            .env("MARIADB_EXPORTER_STATEMENTS_SCHEMAS")
+            .value_name("app1,app2,...")
+            .value_delimiter(',')
+            .action(ArgAction::Append),
+    )
+    .arg(
+        Arg::new("schema.engines")
+            .long("schema.engines")
+            .help("Comma-separated list of storage engines the schema collector reports table sizes for, case-insensitive (default: all)")
+            .env("MARIADB_EXPORTER_SCHEMA_ENGINES")
+            .value_name("innodb,aria,...")
+            .value_delimiter(',')
+            .action(ArgAction::Append),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::commands;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_include_databases() {
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--include-databases",
+            "app1,app2",
+            "--include-databases",
+            "app3",
+        ]);
+
+        let includes: Vec<String> = matches
+            .get_many::<String>("include-databases")
+            .unwrap()
+            .map(std::string::ToString::to_string)
+            .collect();
+        assert_eq!(includes, vec!["app1", "app2", "app3"]);
+    }
+
+    #[test]
+    fn test_include_databases_default_is_empty() {
+        temp_env::with_var("MARIADB_EXPORTER_INCLUDE_DATABASES", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert!(matches.get_many::<String>("include-databases").is_none());
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_statements_schemas() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_STATEMENTS_SCHEMAS",
+            None::<String>,
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert!(matches.get_many::<String>("statements.schemas").is_none());
+
+                let matches = commands::new().get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--statements.schemas",
+                    "app1,app2",
+                ]);
+                let schemas: Vec<&String> = matches
+                    .get_many::<String>("statements.schemas")
+                    .unwrap()
+                    .collect();
+                assert_eq!(schemas, vec!["app1", "app2"]);
+            },
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_schema_engines() {
+        temp_env::with_var("MARIADB_EXPORTER_SCHEMA_ENGINES", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert!(matches.get_many::<String>("schema.engines").is_none());
+
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--schema.engines",
+                "InnoDB,aria",
+            ]);
+            let engines: Vec<&String> = matches
+                .get_many::<String>("schema.engines")
+                .unwrap()
+                .collect();
+            assert_eq!(engines, vec!["InnoDB", "aria"]);
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_include_databases_env() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_INCLUDE_DATABASES",
+            Some("app1,app2"),
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                let includes: Vec<&String> = matches
+                    .get_many::<String>("include-databases")
+                    .unwrap()
+                    .collect();
+                assert_eq!(includes, vec!["app1", "app2"]);
+            },
+        );
+    }
+}
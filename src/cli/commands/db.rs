@@ -0,0 +1,329 @@
+use clap::{Arg, Command, builder::PossibleValuesParser};
+
+pub fn add_db_args(cmd: Command) -> Command {
+    let cmd = cmd.arg(
+        Arg::new("dsn-file")
+            .long("dsn-file")
+            .help("Read the DSN(s) from a file instead of --dsn, re-read on SIGHUP")
+            .long_help(
+                "Read the DSN(s) from a file instead of --dsn: one per line (or\n\
+                 comma-separated), blank lines and # comments ignored.\n\n\
+                 On SIGHUP the file is re-read and the database pools are rebuilt without\n\
+                 restarting the HTTP server, so a rotated password takes effect without a\n\
+                 scrape gap. Scrapes already running finish on the old pool. The set of\n\
+                 targets is fixed at startup; DSNs for new host:port pairs are ignored.",
+            )
+            .env("MARIADB_EXPORTER_DSN_FILE")
+            .value_name("FILE")
+            .value_parser(clap::value_parser!(std::path::PathBuf)),
+    )
+    .arg(
+        Arg::new("password-file")
+            .long("password-file")
+            .help("Read the database password from a file (e.g. a mounted secret) and add it to every --dsn")
+            .long_help(
+                "Read the database password from a file, e.g. a Kubernetes secret mounted at\n\
+                 /etc/secrets/db-password, and add it to every --dsn, keeping it out of argv\n\
+                 and the environment. A trailing newline is ignored.\n\n\
+                 The DSNs must not carry a password of their own:\n\
+                   --dsn mysql://exporter@db:3306/mysql --password-file /etc/secrets/db-password",
+            )
+            .env("MARIADB_EXPORTER_PASSWORD_FILE")
+            .value_name("FILE")
+            .value_parser(clap::value_parser!(std::path::PathBuf))
+            .conflicts_with("dsn-file"),
+    )
+    .arg(
+        Arg::new("db.max-connections")
+            .long("db.max-connections")
+            .help("Maximum number of connections in the database pool")
+            .default_value("3")
+            .env("MARIADB_EXPORTER_DB_MAX_CONNECTIONS")
+            .value_name("N")
+            .value_parser(clap::value_parser!(u32).range(1..)),
+    )
+    .arg(
+        Arg::new("db.min-connections")
+            .long("db.min-connections")
+            .help("Minimum number of idle connections kept in the database pool")
+            .default_value("0")
+            .env("MARIADB_EXPORTER_DB_MIN_CONNECTIONS")
+            .value_name("N")
+            .value_parser(clap::value_parser!(u32)),
+    )
+    .arg(
+        Arg::new("db.acquire-timeout")
+            .long("db.acquire-timeout")
+            .help("Seconds to wait for a connection from the database pool")
+            .default_value("2")
+            .env("MARIADB_EXPORTER_DB_ACQUIRE_TIMEOUT")
+            .value_name("SECONDS")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+    )
+    .arg(
+        Arg::new("db.connect-retries")
+            .long("db.connect-retries")
+            .help("Retries for the initial database connection before exiting, 0 starts without waiting")
+            .long_help(
+                "Retries for the initial database connection before exiting.\n\n\
+                 With 0 (default) the exporter starts right away and reports mariadb_up 0\n\
+                 until the database is reachable. With N > 0 it waits for the database,\n\
+                 retrying up to N times with exponential backoff starting at\n\
+                 --db.connect-retry-interval, and exits if it is still unreachable.",
+            )
+            .default_value("0")
+            .env("MARIADB_EXPORTER_DB_CONNECT_RETRIES")
+            .value_name("N")
+            .value_parser(clap::value_parser!(u32)),
+    )
+    .arg(
+        Arg::new("db.connect-retry-interval")
+            .long("db.connect-retry-interval")
+            .help("Seconds before the first connection retry, doubled after each attempt (max 60)")
+            .default_value("1")
+            .env("MARIADB_EXPORTER_DB_CONNECT_RETRY_INTERVAL")
+            .value_name("SECONDS")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+    );
+
+    add_db_ssl_args(cmd)
+}
+
+fn add_db_ssl_args(cmd: Command) -> Command {
+    cmd.arg(
+        Arg::new("db.ssl-mode")
+            .long("db.ssl-mode")
+            .help("TLS mode for the database connection, overrides ?ssl-mode= in the DSN")
+            .env("MARIADB_EXPORTER_DB_SSL_MODE")
+            .value_name("MODE")
+            .value_parser(PossibleValuesParser::new([
+                "disabled",
+                "preferred",
+                "required",
+                "verify_ca",
+                "verify_identity",
+            ]))
+            .ignore_case(true),
+    )
+    .arg(
+        Arg::new("db.ssl-ca")
+            .long("db.ssl-ca")
+            .help("CA certificate (PEM) used to verify the database server")
+            .env("MARIADB_EXPORTER_DB_SSL_CA")
+            .value_name("FILE")
+            .value_parser(clap::value_parser!(std::path::PathBuf)),
+    )
+    .arg(
+        Arg::new("db.ssl-cert")
+            .long("db.ssl-cert")
+            .help("Client certificate (PEM) for the database connection, requires --db.ssl-key")
+            .env("MARIADB_EXPORTER_DB_SSL_CERT")
+            .value_name("FILE")
+            .value_parser(clap::value_parser!(std::path::PathBuf))
+            .requires("db.ssl-key"),
+    )
+    .arg(
+        Arg::new("db.ssl-key")
+            .long("db.ssl-key")
+            .help("Client private key (PEM) for the database connection, requires --db.ssl-cert")
+            .env("MARIADB_EXPORTER_DB_SSL_KEY")
+            .value_name("FILE")
+            .value_parser(clap::value_parser!(std::path::PathBuf))
+            .requires("db.ssl-cert"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::commands;
+
+    #[test]
+    fn test_db_pool_defaults() {
+        temp_env::with_vars(
+            [
+                ("MARIADB_EXPORTER_DB_MAX_CONNECTIONS", None::<&str>),
+                ("MARIADB_EXPORTER_DB_MIN_CONNECTIONS", None::<&str>),
+                ("MARIADB_EXPORTER_DB_ACQUIRE_TIMEOUT", None::<&str>),
+            ],
+            || {
+                let command = commands::new();
+                let matches = command.get_matches_from(vec!["mariadb_exporter"]);
+
+                assert_eq!(
+                    matches.get_one::<u32>("db.max-connections").copied(),
+                    Some(3)
+                );
+                assert_eq!(
+                    matches.get_one::<u32>("db.min-connections").copied(),
+                    Some(0)
+                );
+                assert_eq!(
+                    matches.get_one::<u64>("db.acquire-timeout").copied(),
+                    Some(2)
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_db_pool_flags() {
+        let command = commands::new();
+        let matches = command.get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.max-connections",
+            "10",
+            "--db.min-connections",
+            "2",
+            "--db.acquire-timeout",
+            "30",
+        ]);
+
+        assert_eq!(
+            matches.get_one::<u32>("db.max-connections").copied(),
+            Some(10)
+        );
+        assert_eq!(
+            matches.get_one::<u32>("db.min-connections").copied(),
+            Some(2)
+        );
+        assert_eq!(
+            matches.get_one::<u64>("db.acquire-timeout").copied(),
+            Some(30)
+        );
+    }
+
+    #[test]
+    fn test_db_acquire_timeout_from_env() {
+        temp_env::with_var("MARIADB_EXPORTER_DB_ACQUIRE_TIMEOUT", Some("20"), || {
+            let command = commands::new();
+            let matches = command.get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(
+                matches.get_one::<u64>("db.acquire-timeout").copied(),
+                Some(20)
+            );
+        });
+    }
+
+    #[test]
+    fn test_db_connect_retry_flags() {
+        temp_env::with_vars(
+            [
+                ("MARIADB_EXPORTER_DB_CONNECT_RETRIES", None::<&str>),
+                ("MARIADB_EXPORTER_DB_CONNECT_RETRY_INTERVAL", Some("5")),
+            ],
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert_eq!(
+                    matches.get_one::<u32>("db.connect-retries").copied(),
+                    Some(0)
+                );
+                assert_eq!(
+                    matches.get_one::<u64>("db.connect-retry-interval").copied(),
+                    Some(5)
+                );
+
+                let matches = commands::new().get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--db.connect-retries",
+                    "10",
+                ]);
+                assert_eq!(
+                    matches.get_one::<u32>("db.connect-retries").copied(),
+                    Some(10)
+                );
+
+                let result = commands::new().try_get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--db.connect-retry-interval",
+                    "0",
+                ]);
+                assert!(result.is_err(), "Should reject a zero retry interval");
+            },
+        );
+    }
+
+    #[test]
+    fn test_db_ssl_flags() {
+        let command = commands::new();
+        let matches = command.get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.ssl-mode",
+            "VERIFY_CA",
+            "--db.ssl-ca",
+            "/etc/ssl/db-ca.pem",
+        ]);
+
+        assert_eq!(
+            matches.get_one::<String>("db.ssl-mode").map(String::as_str),
+            Some("VERIFY_CA")
+        );
+        assert_eq!(
+            matches.get_one::<std::path::PathBuf>("db.ssl-ca"),
+            Some(&std::path::PathBuf::from("/etc/ssl/db-ca.pem"))
+        );
+
+        let command = commands::new();
+        let result = command.try_get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.ssl-cert",
+            "/etc/ssl/client.pem",
+        ]);
+        assert!(result.is_err(), "Client certificate requires a key");
+    }
+
+    #[test]
+    fn test_db_max_connections_rejects_zero() {
+        let command = commands::new();
+        let result =
+            command.try_get_matches_from(vec!["mariadb_exporter", "--db.max-connections", "0"]);
+        assert!(result.is_err(), "Should reject a pool without connections");
+    }
+
+    #[test]
+    fn test_dsn_file() {
+        temp_env::with_var("MARIADB_EXPORTER_DSN_FILE", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert!(matches.get_one::<std::path::PathBuf>("dsn-file").is_none());
+
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--dsn-file",
+                "/run/secrets/mariadb_dsn",
+            ]);
+            assert_eq!(
+                matches.get_one::<std::path::PathBuf>("dsn-file"),
+                Some(&std::path::PathBuf::from("/run/secrets/mariadb_dsn"))
+            );
+        });
+    }
+
+    #[test]
+    fn test_password_file() {
+        temp_env::with_vars(
+            [
+                ("MARIADB_EXPORTER_PASSWORD_FILE", None::<String>),
+                ("MARIADB_EXPORTER_DSN_FILE", None::<String>),
+            ],
+            || {
+                let matches = commands::new().get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--password-file",
+                    "/etc/secrets/db-password",
+                ]);
+                assert_eq!(
+                    matches.get_one::<std::path::PathBuf>("password-file"),
+                    Some(&std::path::PathBuf::from("/etc/secrets/db-password"))
+                );
+
+                let result = commands::new().try_get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--password-file",
+                    "/etc/secrets/db-password",
+                    "--dsn-file",
+                    "/run/secrets/mariadb_dsn",
+                ]);
+                assert!(result.is_err(), "--dsn-file DSNs carry their own password");
+            },
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use clap::Command;
+
+pub fn add_list_metrics_subcommand(cmd: Command) -> Command {
+    cmd.subcommand(
+        Command::new("list-metrics")
+            .about("Print every metric the exporter can emit as JSON, then exit")
+            .long_about(
+                "Register the core metrics and every collector, enabled or not, without\n\
+                 connecting to a database, and print each metric's name, type, help text and\n\
+                 collector as a JSON array sorted by name. Exits non-zero if two collectors\n\
+                 register the same metric name. --metric-prefix applies:\n\n\
+                   mariadb_exporter list-metrics",
+            ),
+    )
+}
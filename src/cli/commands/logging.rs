@@ -0,0 +1,60 @@
+use clap::{Arg, Command};
+
+pub fn add_logging_args(cmd: Command) -> Command {
+    cmd.arg(
+        Arg::new("log-format")
+            .long("log-format")
+            .help("Log output format")
+            .default_value("text")
+            .env("MARIADB_EXPORTER_LOG_FORMAT")
+            .value_parser(["text", "json"]),
+    )
+    .arg(
+        Arg::new("log-level")
+            .long("log-level")
+            .help("Log level (overridden by -v/-vv)")
+            .env("MARIADB_EXPORTER_LOG_LEVEL")
+            .value_parser(["error", "warn", "info", "debug", "trace"]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::commands;
+
+    #[test]
+    fn test_log_format_default() {
+        temp_env::with_var("MARIADB_EXPORTER_LOG_FORMAT", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(
+                matches.get_one::<String>("log-format").map(String::as_str),
+                Some("text")
+            );
+        });
+    }
+
+    #[test]
+    fn test_log_format_json_from_env() {
+        temp_env::with_var("MARIADB_EXPORTER_LOG_FORMAT", Some("json"), || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(
+                matches.get_one::<String>("log-format").map(String::as_str),
+                Some("json")
+            );
+        });
+    }
+
+    #[test]
+    fn test_log_format_rejects_unknown() {
+        let result =
+            commands::new().try_get_matches_from(vec!["mariadb_exporter", "--log-format", "xml"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_log_level_rejects_unknown() {
+        let result =
+            commands::new().try_get_matches_from(vec!["mariadb_exporter", "--log-level", "loud"]);
+        assert!(result.is_err());
+    }
+}
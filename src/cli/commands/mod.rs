@@ -4,6 +4,12 @@ use clap::{
 };
 
 mod collectors;
+mod databases;
+mod db;
+mod list_metrics;
+mod logging;
+mod validate;
+mod web;
 
 pub mod built_info {
     #![allow(clippy::doc_markdown)]
@@ -47,14 +53,14 @@ pub fn new() -> Command {
                      - Not specified (default): Binds to [::]:port which accepts both IPv6 and IPv4 connections.\n\
                        Falls back to 0.0.0.0:port if IPv6 is not available on the system.\n\
                      - Specific IPv4: e.g., '0.0.0.0', '127.0.0.1', '192.168.1.100'\n\
-                     - Specific IPv6: e.g., '::', '::1', 'fe80::1'\n\n\
+                     - Specific IPv6: e.g., '::', '::1', 'fe80::1', optionally bracketed ('[::1]')\n\n\
                      Examples:\n\
                        --listen 0.0.0.0       Bind to all IPv4 interfaces only\n\
                        --listen 127.0.0.1     Bind to localhost IPv4 only\n\
-                       --listen ::            Bind to all IPv6 interfaces (typically accepts IPv4 too)\n\
-                       --listen ::1           Bind to localhost IPv6 only\n\n\
-                     Note: Binding to [::] (IPv6 all interfaces) usually accepts both IPv6 and\n\
-                     IPv4 connections through IPv4-mapped IPv6 addresses on dual-stack systems.",
+                       --listen ::            Bind to all interfaces, IPv6 and IPv4 (dual-stack)\n\
+                       --listen [::1]         Bind to localhost IPv6 only\n\n\
+                     Note: '::' is always bound dual-stack, accepting IPv4 connections through\n\
+                     IPv4-mapped IPv6 addresses regardless of the system's bindv6only setting.",
                 )
                 .env("MARIADB_EXPORTER_LISTEN")
                 .value_name("IP"),
@@ -62,7 +68,7 @@ pub fn new() -> Command {
         .arg(
             Arg::new("dsn")
                 .long("dsn")
-                .help("MariaDB connection string (URL format)")
+                .help("MariaDB connection string (URL format), repeat or comma-separate to scrape several servers")
                 .long_help(
                     "MariaDB/MySQL connection string in URL format.\n\n\
                      Basic formats:\n\
@@ -79,20 +85,18 @@ pub fn new() -> Command {
                        --dsn 'mysql:///mysql?user=exporter'\n\
                        --dsn 'mysql:///mysql?socket=/var/run/mysqld/mysqld.sock&user=exporter'\n\n\
                      SSL modes: DISABLED, PREFERRED, REQUIRED, VERIFY_CA, VERIFY_IDENTITY\n\
-                     See: https://mariadb.com/kb/en/using-tls-ssl-with-mariadb-connectors/"
+                     See: https://mariadb.com/kb/en/using-tls-ssl-with-mariadb-connectors/\n\n\
+                     Multiple servers:\n\
+                     Repeat --dsn (or comma-separate the values) to scrape several servers from\n\
+                     one process. Every series then gets an instance=\"host:port\" label; the\n\
+                     first DSN is used for /health.\n\
+                       --dsn mysql://monitor@primary:3306/mysql --dsn mysql://monitor@localhost:3307/mysql"
                 )
                 .default_value("mysql://root@localhost:3306/mysql")
                 .env("MARIADB_EXPORTER_DSN")
-                .value_name("DSN"),
-        )
-        .arg(
-            Arg::new("exclude-databases")
-                .long("exclude-databases")
-                .help("Comma-separated list of databases to exclude (exact/case-sensitive)")
-                .env("MARIADB_EXPORTER_EXCLUDE_DATABASES")
-                .value_name("information_schema,performance_schema,...")
-                .value_delimiter(',') // split CLI and env values by comma
-                .action(ArgAction::Append), // allow repeated flags if desired
+                .value_name("DSN")
+                .value_delimiter(',')
+                .action(ArgAction::Append),
         )
         .arg(
             Arg::new("verbose")
@@ -102,7 +106,19 @@ pub fn new() -> Command {
                 .action(ArgAction::Count),
         );
 
-    collectors::add_collectors_args(cmd)
+    let cmd = web::add_web_args(cmd);
+
+    let cmd = logging::add_logging_args(cmd);
+
+    let cmd = databases::add_database_args(cmd);
+
+    let cmd = db::add_db_args(cmd);
+
+    let cmd = collectors::add_collectors_args(cmd);
+
+    let cmd = validate::add_validate_subcommand(cmd);
+
+    list_metrics::add_list_metrics_subcommand(cmd)
 }
 
 #[cfg(test)]
@@ -177,6 +193,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_subcommand() {
+        let matches = new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--dsn",
+            "mysql://user@db:3306/mysql",
+            "--collector.innodb",
+            "validate",
+        ]);
+
+        assert!(matches.subcommand_matches("validate").is_some());
+        assert!(matches.get_flag("collector.innodb"));
+
+        let matches = new().get_matches_from(vec!["mariadb_exporter", "list-metrics"]);
+        assert!(matches.subcommand_matches("list-metrics").is_some());
+
+        let matches = new().get_matches_from(vec!["mariadb_exporter"]);
+        assert!(matches.subcommand().is_none());
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)]
     fn test_check_exclude_databases_env() {
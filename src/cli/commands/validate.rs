@@ -0,0 +1,15 @@
+use clap::Command;
+
+pub fn add_validate_subcommand(cmd: Command) -> Command {
+    cmd.subcommand(
+        Command::new("validate")
+            .about("Connect, register the enabled collectors and scrape once, then exit")
+            .long_about(
+                "Connect to every --dsn, register the enabled collectors and run one scrape\n\
+                 without starting the HTTP server. Prints the number of metrics produced per\n\
+                 server and exits non-zero if a DSN can't be reached or a collector fails to\n\
+                 register. Flags go before the subcommand:\n\n\
+                   mariadb_exporter --dsn mysql://exporter@db:3306/mysql --collector.innodb validate",
+            ),
+    )
+}
@@ -0,0 +1,73 @@
+use clap::{Arg, ArgAction, Command};
+
+pub fn add_web_args(cmd: Command) -> Command {
+    cmd.arg(
+        Arg::new("web.telemetry-path")
+            .long("web.telemetry-path")
+            .help("Path under which to expose metrics (/health stays fixed)")
+            .default_value("/metrics")
+            .env("MARIADB_EXPORTER_WEB_TELEMETRY_PATH")
+            .value_name("PATH"),
+    )
+    .arg(
+        Arg::new("web.allowed-cidrs")
+            .long("web.allowed-cidrs")
+            .help("Comma-separated CIDRs allowed to scrape metrics, others get 403 (default: all)")
+            .env("MARIADB_EXPORTER_WEB_ALLOWED_CIDRS")
+            .value_name("CIDR,...")
+            .value_delimiter(',')
+            .action(ArgAction::Append),
+    )
+    .arg(
+        Arg::new("web.trusted-proxies")
+            .long("web.trusted-proxies")
+            .help("Comma-separated CIDRs of proxies whose X-Forwarded-For is trusted")
+            .env("MARIADB_EXPORTER_WEB_TRUSTED_PROXIES")
+            .value_name("CIDR,...")
+            .value_delimiter(',')
+            .action(ArgAction::Append),
+    )
+    .arg(
+        Arg::new("cache-ttl")
+            .long("cache-ttl")
+            .help("Serve the last scrape for this many seconds instead of collecting again (0 disables)")
+            .default_value("0")
+            .env("MARIADB_EXPORTER_CACHE_TTL")
+            .value_name("SECONDS")
+            .value_parser(clap::value_parser!(f64)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::commands;
+
+    #[test]
+    fn test_telemetry_path() {
+        temp_env::with_var(
+            "MARIADB_EXPORTER_WEB_TELEMETRY_PATH",
+            None::<String>,
+            || {
+                let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+                assert_eq!(
+                    matches
+                        .get_one::<String>("web.telemetry-path")
+                        .map(String::as_str),
+                    Some("/metrics")
+                );
+
+                let matches = commands::new().get_matches_from(vec![
+                    "mariadb_exporter",
+                    "--web.telemetry-path",
+                    "/db-metrics",
+                ]);
+                assert_eq!(
+                    matches
+                        .get_one::<String>("web.telemetry-path")
+                        .map(String::as_str),
+                    Some("/db-metrics")
+                );
+            },
+        );
+    }
+}
@@ -2,12 +2,26 @@ use crate::{
     cli::actions::Action,
     collectors::{
         COLLECTOR_NAMES, Collector, all_factories,
-        util::{get_excluded_databases, set_excluded_databases},
+        util::{
+            DbTlsOptions, get_excluded_databases, get_included_databases, set_datadir,
+            set_db_tls_options, set_excluded_databases, set_included_databases,
+            set_max_series_per_collector, set_metric_prefix, set_schema_engines,
+            set_slow_collector_threshold, set_statements_schemas, set_status_replication_enabled,
+            set_userstat_split_host,
+        },
+    },
+    exporter::{
+        PoolConfig, WebConfig,
+        access::{AccessConfig, parse_cidr},
+        reload::{read_dsn_file, with_password_file},
     },
 };
 use anyhow::{Result, anyhow};
 use clap::ArgMatches;
+use ipnet::IpNet;
 use secrecy::SecretString;
+use sqlx::mysql::MySqlSslMode;
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr, time::Duration};
 use tracing::info;
 
 /// # Errors
@@ -19,6 +33,97 @@ pub fn handler(matches: &clap::ArgMatches) -> Result<Action> {
 
     info!("Excluded databases: {:?}", get_excluded_databases());
 
+    init_included_databases(matches);
+
+    if !get_included_databases().is_empty() {
+        info!("Included databases: {:?}", get_included_databases());
+    }
+
+    init_statements_schemas(matches);
+
+    init_schema_engines(matches);
+
+    set_status_replication_enabled(!matches.get_flag("no-status.replication"));
+
+    set_userstat_split_host(matches.get_flag("userstat.split-host"));
+
+    set_slow_collector_threshold(get_slow_collector_threshold(matches)?);
+
+    set_max_series_per_collector(get_max_series_per_collector(matches));
+
+    if let Some(prefix) = matches.get_one::<String>("metric-prefix") {
+        set_metric_prefix(prefix);
+    }
+
+    // Needs no database: skip the DSN and web configuration
+    if matches.subcommand_matches("list-metrics").is_some() {
+        return Ok(Action::ListMetrics);
+    }
+
+    if let Some(datadir) = matches.get_one::<PathBuf>("datadir") {
+        set_datadir(datadir.clone());
+    }
+
+    set_db_tls_options(get_db_tls_options(matches)?);
+
+    let web = get_web_config(matches)?;
+
+    let dsn_file = matches.get_one::<PathBuf>("dsn-file").cloned();
+
+    let mut dsns = match &dsn_file {
+        Some(path) => read_dsn_file(path)?,
+        None => get_dsns(matches)?,
+    };
+
+    let password_file = matches.get_one::<PathBuf>("password-file").cloned();
+
+    if let Some(path) = &password_file {
+        dsns = with_password_file(&dsns, path)?;
+    }
+
+    let pool = get_pool_config(matches)?;
+
+    let collectors = get_enabled_collectors(matches);
+
+    let external_labels = get_external_labels(matches)?;
+
+    if matches.subcommand_matches("validate").is_some() {
+        return Ok(Action::Validate {
+            dsns,
+            collectors,
+            pool,
+        });
+    }
+
+    Ok(Action::Run {
+        web,
+        dsns,
+        dsn_file,
+        password_file,
+        collectors,
+        external_labels,
+        pool,
+    })
+}
+
+/// `--external-label` pairs by key; a key given twice is an error rather than last-wins.
+fn get_external_labels(matches: &ArgMatches) -> Result<BTreeMap<String, String>> {
+    let mut labels = BTreeMap::new();
+
+    for (key, value) in matches
+        .get_many::<(String, String)>("external-label")
+        .into_iter()
+        .flatten()
+    {
+        if labels.insert(key.clone(), value.clone()).is_some() {
+            return Err(anyhow!("--external-label {key} given more than once"));
+        }
+    }
+
+    Ok(labels)
+}
+
+fn get_web_config(matches: &ArgMatches) -> Result<WebConfig> {
     // Get the port or return an error
     let port = matches
         .get_one::<u16>("port")
@@ -30,22 +135,169 @@ pub fn handler(matches: &clap::ArgMatches) -> Result<Action> {
         .get_one::<String>("listen")
         .map(std::string::ToString::to_string);
 
-    // Get the DSN or return an error
-    let dsn = SecretString::from(
-        matches
-            .get_one::<String>("dsn")
-            .cloned()
-            .ok_or_else(|| anyhow!("DSN is required. Please provide it using the --dsn flag."))?,
-    );
+    let access = AccessConfig {
+        allowed_cidrs: get_cidrs(matches, "web.allowed-cidrs")?,
+        trusted_proxies: get_cidrs(matches, "web.trusted-proxies")?,
+    };
 
-    Ok(Action::Run {
+    Ok(WebConfig {
         port,
         listen,
-        dsn,
-        collectors: get_enabled_collectors(matches),
+        telemetry_path: get_telemetry_path(matches)?,
+        access,
+        cache_ttl: get_cache_ttl(matches)?,
+    })
+}
+
+fn get_cache_ttl(matches: &ArgMatches) -> Result<Duration> {
+    let seconds = matches.get_one::<f64>("cache-ttl").copied().unwrap_or(0.0);
+
+    if seconds < 0.0 {
+        return Err(anyhow!("--cache-ttl can't be negative, got {seconds}"));
+    }
+
+    Duration::try_from_secs_f64(seconds).map_err(|e| anyhow!("invalid --cache-ttl {seconds}: {e}"))
+}
+
+fn get_cidrs(matches: &ArgMatches, id: &str) -> Result<Vec<IpNet>> {
+    matches
+        .get_many::<String>(id)
+        .into_iter()
+        .flatten()
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| parse_cidr(s).map_err(|e| anyhow!("--{id}: {e}")))
+        .collect()
+}
+
+fn get_telemetry_path(matches: &ArgMatches) -> Result<String> {
+    let path = matches
+        .get_one::<String>("web.telemetry-path")
+        .map_or("/metrics", |p| p.trim());
+
+    if !path.starts_with('/') || path.len() < 2 {
+        return Err(anyhow!(
+            "--web.telemetry-path must start with '/' and not be the root, got '{path}'"
+        ));
+    }
+
+    if path == "/health" {
+        return Err(anyhow!("--web.telemetry-path can't be /health"));
+    }
+
+    Ok(path.to_string())
+}
+
+/// One DSN per server to scrape, from repeated or comma-separated `--dsn` values.
+fn get_dsns(matches: &ArgMatches) -> Result<Vec<SecretString>> {
+    let dsns: Vec<SecretString> = matches
+        .get_many::<String>("dsn")
+        .map(|vals| {
+            vals.map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(|s| SecretString::from(s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if dsns.is_empty() {
+        return Err(anyhow!(
+            "DSN is required. Please provide it using the --dsn flag."
+        ));
+    }
+
+    Ok(dsns)
+}
+
+fn get_db_tls_options(matches: &ArgMatches) -> Result<DbTlsOptions> {
+    let ssl_mode = matches
+        .get_one::<String>("db.ssl-mode")
+        .map(|mode| MySqlSslMode::from_str(mode))
+        .transpose()
+        .map_err(|e| anyhow!("invalid --db.ssl-mode: {e}"))?;
+
+    // Fail at startup rather than on the first scrape
+    let file = |id: &str| -> Result<Option<PathBuf>> {
+        match matches.get_one::<PathBuf>(id) {
+            Some(path) if !path.is_file() => {
+                Err(anyhow!("--{id}: file not found: {}", path.display()))
+            }
+            path => Ok(path.cloned()),
+        }
+    };
+
+    Ok(DbTlsOptions {
+        ssl_mode,
+        ssl_ca: file("db.ssl-ca")?,
+        ssl_cert: file("db.ssl-cert")?,
+        ssl_key: file("db.ssl-key")?,
+    })
+}
+
+fn get_pool_config(matches: &ArgMatches) -> Result<PoolConfig> {
+    let defaults = PoolConfig::default();
+
+    let max_connections = matches
+        .get_one::<u32>("db.max-connections")
+        .copied()
+        .unwrap_or(defaults.max_connections);
+
+    let min_connections = matches
+        .get_one::<u32>("db.min-connections")
+        .copied()
+        .unwrap_or(defaults.min_connections);
+
+    if min_connections > max_connections {
+        return Err(anyhow!(
+            "--db.min-connections ({min_connections}) must not exceed --db.max-connections ({max_connections})"
+        ));
+    }
+
+    let acquire_timeout = matches
+        .get_one::<u64>("db.acquire-timeout")
+        .copied()
+        .map_or(defaults.acquire_timeout, Duration::from_secs);
+
+    let connect_retries = matches
+        .get_one::<u32>("db.connect-retries")
+        .copied()
+        .unwrap_or(defaults.connect_retries);
+
+    let connect_retry_interval = matches
+        .get_one::<u64>("db.connect-retry-interval")
+        .copied()
+        .map_or(defaults.connect_retry_interval, Duration::from_secs);
+
+    Ok(PoolConfig {
+        max_connections,
+        min_connections,
+        acquire_timeout,
+        connect_retries,
+        connect_retry_interval,
     })
 }
 
+fn get_slow_collector_threshold(matches: &ArgMatches) -> Result<Duration> {
+    let seconds = matches
+        .get_one::<f64>("slow-collector-threshold")
+        .copied()
+        .unwrap_or(1.0);
+
+    if seconds <= 0.0 {
+        return Err(anyhow!(
+            "--slow-collector-threshold must be greater than 0, got {seconds}"
+        ));
+    }
+
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|e| anyhow!("invalid --slow-collector-threshold {seconds}: {e}"))
+}
+
+fn get_max_series_per_collector(matches: &ArgMatches) -> Option<usize> {
+    matches
+        .get_one::<u64>("max-series-per-collector")
+        .map(|&rows| usize::try_from(rows).unwrap_or(usize::MAX))
+}
+
 fn init_excluded_databases(matches: &ArgMatches) {
     // Collect values from Clap (supports --exclude-databases a,b and env)
     let excludes: Vec<String> = matches
@@ -61,6 +313,56 @@ fn init_excluded_databases(matches: &ArgMatches) {
     set_excluded_databases(excludes);
 }
 
+fn init_included_databases(matches: &ArgMatches) {
+    // Empty (the default) means every database is collected
+    let includes: Vec<String> = matches
+        .get_many::<String>("include-databases")
+        .map(|vals| {
+            vals.map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    set_included_databases(includes);
+}
+
+fn init_statements_schemas(matches: &ArgMatches) {
+    // Empty (the default) keeps top digests from every schema
+    let schemas: Vec<String> = matches
+        .get_many::<String>("statements.schemas")
+        .map(|vals| {
+            vals.map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if !schemas.is_empty() {
+        info!("Statements schemas: {:?}", schemas);
+    }
+
+    set_statements_schemas(schemas);
+}
+
+fn init_schema_engines(matches: &ArgMatches) {
+    // Empty (the default) reports tables of every engine
+    let engines: Vec<String> = matches
+        .get_many::<String>("schema.engines")
+        .map(|vals| {
+            vals.map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if !engines.is_empty() {
+        info!("Schema engines: {:?}", engines);
+    }
+
+    set_schema_engines(engines);
+}
+
 #[must_use]
 pub fn get_enabled_collectors(matches: &ArgMatches) -> Vec<String> {
     let factories = all_factories();
@@ -124,6 +426,196 @@ mod tests {
         assert!(!enabled.contains(&"default".to_string()));
     }
 
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_pool_config_from_flags() {
+        let command = commands::new();
+        let matches = command.get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.max-connections",
+            "8",
+            "--db.min-connections",
+            "1",
+            "--db.acquire-timeout",
+            "10",
+            "--db.connect-retries",
+            "5",
+            "--db.connect-retry-interval",
+            "3",
+        ]);
+        let pool = get_pool_config(&matches).unwrap();
+
+        assert_eq!(pool.max_connections, 8);
+        assert_eq!(pool.min_connections, 1);
+        assert_eq!(pool.acquire_timeout, Duration::from_secs(10));
+        assert_eq!(pool.connect_retries, 5);
+        assert_eq!(pool.connect_retry_interval, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_get_pool_config_rejects_min_above_max() {
+        let command = commands::new();
+        let matches = command.get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.max-connections",
+            "2",
+            "--db.min-connections",
+            "5",
+        ]);
+
+        assert!(get_pool_config(&matches).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_db_tls_options() {
+        let ca = std::env::temp_dir().join("mariadb_exporter_test_ca.pem");
+        std::fs::write(&ca, "-----BEGIN CERTIFICATE-----\n").unwrap();
+        let ca_arg = ca.to_string_lossy().to_string();
+
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.ssl-mode",
+            "verify_identity",
+            "--db.ssl-ca",
+            ca_arg.as_str(),
+        ]);
+        let tls = get_db_tls_options(&matches).unwrap();
+        assert!(matches!(tls.ssl_mode, Some(MySqlSslMode::VerifyIdentity)));
+        assert_eq!(tls.ssl_ca, Some(ca.clone()));
+        assert!(tls.ssl_cert.is_none());
+
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--db.ssl-ca",
+            "/nonexistent/ca.pem",
+        ]);
+        assert!(get_db_tls_options(&matches).is_err());
+
+        let _ = std::fs::remove_file(ca);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_web_config_access() {
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--web.allowed-cidrs",
+            "10.0.0.0/8,192.168.1.5",
+            "--web.trusted-proxies",
+            "172.16.0.1",
+        ]);
+        let web = get_web_config(&matches).unwrap();
+
+        let allowed: Vec<String> = web
+            .access
+            .allowed_cidrs
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(allowed, vec!["10.0.0.0/8", "192.168.1.5/32"]);
+        assert_eq!(web.access.trusted_proxies.len(), 1);
+
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--web.allowed-cidrs",
+            "10.0.0.0/40",
+        ]);
+        assert!(get_web_config(&matches).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_cache_ttl() {
+        temp_env::with_var("MARIADB_EXPORTER_CACHE_TTL", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(get_cache_ttl(&matches).unwrap(), Duration::ZERO);
+
+            let matches =
+                commands::new().get_matches_from(vec!["mariadb_exporter", "--cache-ttl", "2.5"]);
+            assert_eq!(
+                get_cache_ttl(&matches).unwrap(),
+                Duration::from_millis(2500)
+            );
+
+            let matches =
+                commands::new().get_matches_from(vec!["mariadb_exporter", "--cache-ttl=-1"]);
+            assert!(get_cache_ttl(&matches).is_err());
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_telemetry_path() {
+        let path = |value: &str| {
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--web.telemetry-path",
+                value,
+            ]);
+            get_telemetry_path(&matches)
+        };
+
+        assert_eq!(path("/db-metrics").unwrap(), "/db-metrics");
+        assert!(path("db-metrics").is_err());
+        assert!(path("/").is_err());
+        assert!(path("/health").is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_dsns_repeated_and_comma_separated() {
+        use secrecy::ExposeSecret;
+
+        temp_env::with_var("MARIADB_EXPORTER_DSN", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--dsn",
+                "mysql://root@primary:3306/mysql,mysql://root@localhost:3307/mysql",
+                "--dsn",
+                "mysql://root@backup:3306/mysql",
+            ]);
+            let dsns: Vec<String> = get_dsns(&matches)
+                .unwrap()
+                .iter()
+                .map(|d| d.expose_secret().to_string())
+                .collect();
+
+            assert_eq!(
+                dsns,
+                vec![
+                    "mysql://root@primary:3306/mysql",
+                    "mysql://root@localhost:3307/mysql",
+                    "mysql://root@backup:3306/mysql",
+                ]
+            );
+
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(get_dsns(&matches).unwrap().len(), 1);
+        });
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_slow_collector_threshold() {
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--slow-collector-threshold",
+            "0.5",
+        ]);
+        assert_eq!(
+            get_slow_collector_threshold(&matches).unwrap(),
+            Duration::from_millis(500)
+        );
+
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--slow-collector-threshold",
+            "0",
+        ]);
+        assert!(get_slow_collector_threshold(&matches).is_err());
+    }
+
     #[test]
     fn test_get_enabled_collectors_disable_all_defaults() {
         let command = commands::new();
@@ -132,4 +624,33 @@ mod tests {
 
         assert!(!enabled.contains(&"default".to_string()));
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_external_labels() {
+        temp_env::with_var("MARIADB_EXPORTER_EXTERNAL_LABELS", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--external-label",
+                "region=us,env=prod",
+            ]);
+            let labels = get_external_labels(&matches).unwrap();
+            assert_eq!(
+                labels.into_iter().collect::<Vec<_>>(),
+                vec![
+                    ("env".to_string(), "prod".to_string()),
+                    ("region".to_string(), "us".to_string()),
+                ]
+            );
+
+            let matches = commands::new().get_matches_from(vec![
+                "mariadb_exporter",
+                "--external-label",
+                "env=prod",
+                "--external-label",
+                "env=staging",
+            ]);
+            assert!(get_external_labels(&matches).is_err());
+        });
+    }
 }
@@ -1,5 +1,11 @@
-use crate::cli::{actions::Action, commands, dispatch::handler, telemetry};
+use crate::cli::{
+    actions::Action,
+    commands,
+    dispatch::handler,
+    telemetry::{self, LogFormat},
+};
 use anyhow::Result;
+use std::str::FromStr;
 
 /// Map verbosity count to tracing level
 const fn get_verbosity_level(verbose_count: u8) -> Option<tracing::Level> {
@@ -11,6 +17,19 @@ const fn get_verbosity_level(verbose_count: u8) -> Option<tracing::Level> {
     }
 }
 
+/// Resolve the log level: `-v` flags win, then `--log-level`, then the default (error)
+fn get_log_level(matches: &clap::ArgMatches) -> Result<Option<tracing::Level>> {
+    if let Some(level) = get_verbosity_level(matches.get_count("verbose")) {
+        return Ok(Some(level));
+    }
+
+    matches
+        .get_one::<String>("log-level")
+        .map(|level| tracing::Level::from_str(level))
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("invalid --log-level: {e}"))
+}
+
 /// Start the CLI
 ///
 /// # Errors
@@ -19,9 +38,13 @@ const fn get_verbosity_level(verbose_count: u8) -> Option<tracing::Level> {
 pub fn start() -> Result<Action> {
     let matches = commands::new().get_matches();
 
-    let verbosity_level = get_verbosity_level(matches.get_count("verbose"));
+    let verbosity_level = get_log_level(&matches)?;
 
-    telemetry::init(verbosity_level)?;
+    let log_format = matches
+        .get_one::<String>("log-format")
+        .map_or(Ok(LogFormat::default()), |f| LogFormat::from_str(f))?;
+
+    telemetry::init(verbosity_level, log_format)?;
 
     let action = handler(&matches)?;
 
@@ -59,4 +82,36 @@ mod tests {
         assert_eq!(get_verbosity_level(10), Some(tracing::Level::TRACE));
         assert_eq!(get_verbosity_level(255), Some(tracing::Level::TRACE));
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_log_level_from_flag() {
+        let matches =
+            commands::new().get_matches_from(vec!["mariadb_exporter", "--log-level", "warn"]);
+        assert_eq!(get_log_level(&matches).unwrap(), Some(tracing::Level::WARN));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_log_level_verbose_wins() {
+        let matches = commands::new().get_matches_from(vec![
+            "mariadb_exporter",
+            "--log-level",
+            "warn",
+            "-vv",
+        ]);
+        assert_eq!(
+            get_log_level(&matches).unwrap(),
+            Some(tracing::Level::DEBUG)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_get_log_level_default() {
+        temp_env::with_var("MARIADB_EXPORTER_LOG_LEVEL", None::<String>, || {
+            let matches = commands::new().get_matches_from(vec!["mariadb_exporter"]);
+            assert_eq!(get_log_level(&matches).unwrap(), None);
+        });
+    }
 }
@@ -15,11 +15,53 @@ use tonic::{
     transport::ClientTlsConfig,
 };
 use tracing::{Level, debug};
-use tracing_subscriber::{EnvFilter, Registry, fmt, layer::SubscriberExt};
+use tracing_subscriber::{EnvFilter, Layer, Registry, fmt, layer::SubscriberExt};
 use ulid::Ulid;
 
 static TRACER_PROVIDER: OnceCell<SdkTracerProvider> = OnceCell::new();
 
+/// Output format for log lines written to stdout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable, multi-line output (default)
+    #[default]
+    Text,
+    /// One JSON object per line, for log shippers such as Loki
+    Json,
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => Err(anyhow!(
+                "invalid log format '{other}', expected 'text' or 'json'"
+            )),
+        }
+    }
+}
+
+fn fmt_layer(format: LogFormat) -> Box<dyn Layer<Registry> + Send + Sync> {
+    let layer = fmt::layer()
+        .with_file(false)
+        .with_line_number(false)
+        .with_thread_ids(false)
+        .with_thread_names(false)
+        .with_target(false);
+
+    match format {
+        LogFormat::Text => layer.pretty().boxed(),
+        LogFormat::Json => layer
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .boxed(),
+    }
+}
+
 fn parse_headers_env(headers_str: &str) -> HashMap<String, String> {
     headers_str
         .split(',')
@@ -157,16 +199,10 @@ fn init_tracer() -> Result<Tracer> {
 /// # Errors
 ///
 /// Returns an error if tracer or subscriber initialization fails
-pub fn init(verbosity_level: Option<Level>) -> Result<()> {
+pub fn init(verbosity_level: Option<Level>, log_format: LogFormat) -> Result<()> {
     let verbosity_level = verbosity_level.unwrap_or(Level::ERROR);
 
-    let fmt_layer = fmt::layer()
-        .with_file(false)
-        .with_line_number(false)
-        .with_thread_ids(false)
-        .with_thread_names(false)
-        .with_target(false)
-        .pretty();
+    let fmt_layer = fmt_layer(log_format);
 
     let filter = EnvFilter::builder()
         .with_default_directive(verbosity_level.into())
@@ -344,6 +380,20 @@ mod tests {
         assert_eq!(result, "https://api.example.com:4317/v1/traces");
     }
 
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_log_format_from_str() {
+        assert_eq!("text".parse::<LogFormat>().unwrap(), LogFormat::Text);
+        assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert_eq!("JSON".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert!("logfmt".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn test_log_format_default_is_text() {
+        assert_eq!(LogFormat::default(), LogFormat::Text);
+    }
+
     #[test]
     fn test_shutdown_tracer_no_provider() {
         // Should not panic when no provider is initialized
@@ -0,0 +1,202 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{PICO_TO_SECONDS, is_access_denied, metric_name, series_limit},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{GaugeVec, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Accounts exported per scrape, by total latency, to bound cardinality
+/// (overridden by `--max-series-per-collector`).
+const TOP_ACCOUNTS: usize = 25;
+
+/// Background threads have a NULL `USER` and are left out.
+const ACCOUNTS_QUERY: &str = "SELECT USER, COALESCE(HOST, ''),
+        CAST(SUM(COUNT_STAR) AS UNSIGNED), CAST(SUM(SUM_TIMER_WAIT) AS UNSIGNED)
+     FROM performance_schema.events_statements_summary_by_account_by_event_name
+     WHERE USER IS NOT NULL
+     GROUP BY USER, HOST
+     HAVING SUM(COUNT_STAR) > 0
+     ORDER BY SUM(SUM_TIMER_WAIT) DESC
+     LIMIT ?";
+
+/// Statements per account (`user@host`) from
+/// `performance_schema.events_statements_summary_by_account_by_event_name` (opt-in).
+///
+/// The account-centric companion to the digest-centric `statements` collector, for
+/// attributing load to tenants. Limited to the accounts with the most statement latency.
+#[derive(Clone)]
+pub struct AccountStatementsCollector {
+    statements: IntGaugeVec,
+    latency_seconds: GaugeVec,
+}
+
+impl AccountStatementsCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new account statements collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            statements: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_account_statements_total"),
+                    "Number of statements executed per account, across all statement types",
+                ),
+                &["user", "host"],
+            )
+            .expect("valid mariadb_perf_schema_account_statements_total metric"),
+            latency_seconds: GaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_account_statements_latency_seconds_total"),
+                    "Total statement latency per account, picoseconds converted to seconds",
+                ),
+                &["user", "host"],
+            )
+            .expect("valid mariadb_perf_schema_account_statements_latency_seconds_total metric"),
+        }
+    }
+
+    fn set_account(&self, user: &str, host: &str, count: u64, timer_ps: u64) {
+        self.statements
+            .with_label_values(&[user, host])
+            .set(i64::try_from(count).unwrap_or(i64::MAX));
+
+        #[allow(clippy::cast_precision_loss)]
+        self.latency_seconds
+            .with_label_values(&[user, host])
+            .set(timer_ps as f64 / PICO_TO_SECONDS);
+    }
+}
+
+impl Default for AccountStatementsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for AccountStatementsCollector {
+    fn name(&self) -> &'static str {
+        "account_statements"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "account_statements")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.statements.clone()))?;
+        registry.register(Box::new(self.latency_seconds.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "account_statements", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // The top accounts change between scrapes; drop the ones that fell out
+            self.statements.reset();
+            self.latency_seconds.reset();
+
+            // Confirm table exists (Performance Schema might be off)
+            let exists_span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "check events_statements_summary_by_account_by_event_name table",
+                otel.kind = "client"
+            );
+
+            let has_table = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema='performance_schema' AND table_name='events_statements_summary_by_account_by_event_name'",
+            )
+            .fetch_one(pool)
+            .instrument(exists_span)
+            .await
+            .unwrap_or(0)
+                > 0;
+
+            if !has_table {
+                debug!(
+                    "events_statements_summary_by_account_by_event_name not available; skipping collection"
+                );
+                return Ok(());
+            }
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = ACCOUNTS_QUERY,
+                otel.kind = "client"
+            );
+
+            let rows = match sqlx::query_as::<_, (String, String, u64, u64)>(ACCOUNTS_QUERY)
+                .bind(u64::try_from(series_limit(TOP_ACCOUNTS)).unwrap_or(u64::MAX))
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => {
+                    debug!(error = %e, "account statements query failed; skipping");
+                    return Ok(());
+                }
+            };
+
+            for (user, host, count, timer_ps) in rows {
+                self.set_account(&user, &host, count, timer_ps);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_account() {
+        let collector = AccountStatementsCollector::new();
+        collector.set_account("tenant_a", "10.0.0.%", 1200, 2_500_000_000_000);
+        collector.set_account("tenant_b", "", u64::MAX, 0);
+
+        assert_eq!(
+            collector
+                .statements
+                .with_label_values(&["tenant_a", "10.0.0.%"])
+                .get(),
+            1200
+        );
+        assert!(
+            (collector
+                .latency_seconds
+                .with_label_values(&["tenant_a", "10.0.0.%"])
+                .get()
+                - 2.5)
+                .abs()
+                < f64::EPSILON
+        );
+        assert_eq!(
+            collector
+                .statements
+                .with_label_values(&["tenant_b", ""])
+                .get(),
+            i64::MAX
+        );
+    }
+}
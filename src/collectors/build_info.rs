@@ -0,0 +1,124 @@
+//! `mariadb_exporter_build_info`, registered once by every `CollectorRegistry` regardless of
+//! the enabled collectors.
+
+use crate::collectors::{Registrar, util::metric_name};
+use crate::exporter::{GIT_COMMIT_HASH, GIT_DIRTY};
+use anyhow::Result;
+use prometheus::{GaugeVec, Opts};
+use std::env;
+use tracing::info;
+
+/// Build-time override for the `dirty` label, e.g. `MARIADB_EXPORTER_BUILD_DIRTY=true cargo build`
+/// for canary builds made from a clean checkout.
+const DIRTY_OVERRIDE: Option<&str> = option_env!("MARIADB_EXPORTER_BUILD_DIRTY");
+
+/// Exporter build information: version, commit, architecture and whether the tree was dirty.
+#[derive(Clone)]
+pub struct BuildInfoCollector {
+    build_info: GaugeVec,
+}
+
+impl BuildInfoCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create the build info gauge, already set to 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        let build_info = GaugeVec::new(
+            Opts::new(
+                metric_name("exporter_build_info"),
+                "Build information for mariadb_exporter",
+            ),
+            &["version", "commit", "arch", "dirty"],
+        )
+        .expect("valid mariadb_exporter_build_info metric");
+
+        let version = env!("CARGO_PKG_VERSION");
+        let commit_sha = GIT_COMMIT_HASH.unwrap_or("unknown");
+        let dirty = dirty_label(DIRTY_OVERRIDE, GIT_DIRTY);
+
+        build_info
+            .with_label_values(&[version, commit_sha, env::consts::ARCH, dirty])
+            .set(1.0);
+
+        info!(
+            "Registered mariadb_exporter_build_info: version={} commit={} dirty={}",
+            version, commit_sha, dirty
+        );
+
+        Self { build_info }
+    }
+
+    /// Get build info metric.
+    #[must_use]
+    pub const fn build_info(&self) -> &GaugeVec {
+        &self.build_info
+    }
+
+    /// Register the build info gauge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry already has a `mariadb_exporter_build_info` metric.
+    pub fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.build_info.clone()))?;
+        Ok(())
+    }
+}
+
+impl Default for BuildInfoCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The explicit build-time override wins over what git reported.
+fn dirty_label(override_value: Option<&str>, git_dirty: Option<bool>) -> &'static str {
+    match override_value.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(v) if v == "1" || v.eq_ignore_ascii_case("true") => "true",
+        Some(_) => "false",
+        None => match git_dirty {
+            Some(true) => "true",
+            Some(false) => "false",
+            None => "unknown",
+        },
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use prometheus::Registry;
+
+    #[test]
+    fn test_dirty_label() {
+        assert_eq!(dirty_label(None, Some(true)), "true");
+        assert_eq!(dirty_label(None, Some(false)), "false");
+        assert_eq!(dirty_label(None, None), "unknown");
+        assert_eq!(dirty_label(Some("true"), Some(false)), "true");
+        assert_eq!(dirty_label(Some("1"), None), "true");
+        assert_eq!(dirty_label(Some("false"), Some(true)), "false");
+        assert_eq!(dirty_label(Some(" "), Some(true)), "true");
+    }
+
+    #[test]
+    fn test_register_metrics_only_once() {
+        let registry = Registry::new();
+        let build_info = BuildInfoCollector::new();
+
+        build_info.register_metrics(&registry).unwrap();
+        assert!(
+            BuildInfoCollector::new()
+                .register_metrics(&registry)
+                .is_err()
+        );
+
+        let families = registry.gather();
+        assert_eq!(families.len(), 1);
+        assert!(families.iter().all(|mf| mf.get_metric().len() == 1));
+    }
+}
@@ -0,0 +1,175 @@
+//! The metric catalog printed by `list-metrics`: every metric the exporter can emit, with its
+//! type and help text, gathered without a database.
+
+use crate::collectors::{
+    COLLECTOR_NAMES, Collector, Registrar, all_factories, config::CollectorConfig,
+    registry::register_core_metrics, target_info::TargetInfoCollector,
+};
+use anyhow::{Context, Result};
+use prometheus::{
+    Registry,
+    proto::{MetricFamily, MetricType},
+};
+use serde::Serialize;
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+/// One catalog entry.
+#[derive(Serialize, Debug)]
+pub struct MetricInfo {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub metric_type: &'static str,
+    pub help: String,
+    /// Collector that exports the metric; absent for the metrics every exporter has
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collector: Option<&'static str>,
+}
+
+/// A `Registrar` that keeps every registered family, including label vectors that have no
+/// series yet (`Registry::gather` skips those), and rejects duplicate names like a `Registry`.
+#[derive(Default)]
+pub struct MetricCatalog {
+    registry: Registry,
+    collector: Cell<Option<&'static str>>,
+    entries: RefCell<Vec<MetricInfo>>,
+}
+
+impl Registrar for MetricCatalog {
+    fn register(&self, collector: Box<dyn prometheus::core::Collector>) -> prometheus::Result<()> {
+        let families = collector.collect();
+
+        self.registry.register(collector)?;
+
+        self.entries
+            .borrow_mut()
+            .extend(families.iter().map(|family| MetricInfo {
+                name: family.name().to_string(),
+                metric_type: type_name(family),
+                help: family.help().to_string(),
+                collector: self.collector.get(),
+            }));
+
+        Ok(())
+    }
+}
+
+impl MetricCatalog {
+    /// The registered metrics, sorted by name.
+    #[must_use]
+    pub fn into_entries(self) -> Vec<MetricInfo> {
+        let mut entries = self.entries.into_inner();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        entries
+    }
+}
+
+/// Register the core metrics and every collector, enabled by default or not, into a
+/// `MetricCatalog`.
+///
+/// # Errors
+///
+/// Returns an error if a collector fails to register, e.g. because a metric name is already
+/// taken by another collector
+pub fn metric_catalog() -> Result<Vec<MetricInfo>> {
+    let catalog = MetricCatalog::default();
+
+    register_core_metrics(&CollectorConfig::new(), &catalog);
+
+    // Its labels come from --external-label; registered unlabelled to list it anyway
+    TargetInfoCollector::new(&BTreeMap::new())?.register_metrics(&catalog)?;
+
+    let factories = all_factories();
+
+    for name in COLLECTOR_NAMES {
+        if let Some(factory) = factories.get(name) {
+            catalog.collector.set(Some(name));
+            factory()
+                .register_metrics(&catalog)
+                .with_context(|| format!("collector '{name}' failed to register"))?;
+        }
+    }
+
+    Ok(catalog.into_entries())
+}
+
+fn type_name(family: &MetricFamily) -> &'static str {
+    match family.get_field_type() {
+        MetricType::COUNTER => "counter",
+        MetricType::GAUGE => "gauge",
+        MetricType::SUMMARY => "summary",
+        MetricType::UNTYPED => "untyped",
+        MetricType::HISTOGRAM => "histogram",
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::collectors::util::metric_name;
+    use prometheus::{IntGauge, IntGaugeVec, Opts};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_catalog_keeps_empty_label_vectors() {
+        let catalog = MetricCatalog::default();
+        let vec = IntGaugeVec::new(Opts::new("test_vec", "A vector"), &["label"]).unwrap();
+
+        catalog.register(Box::new(vec)).unwrap();
+
+        let entries = catalog.into_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries.first().map(|e| e.metric_type), Some("gauge"));
+    }
+
+    #[test]
+    fn test_catalog_rejects_duplicate_names() {
+        let catalog = MetricCatalog::default();
+
+        catalog
+            .register(Box::new(IntGauge::new("test_dup", "First").unwrap()))
+            .unwrap();
+        assert!(
+            catalog
+                .register(Box::new(IntGauge::new("test_dup", "Second").unwrap()))
+                .is_err()
+        );
+        assert_eq!(catalog.into_entries().len(), 1);
+    }
+
+    #[test]
+    fn test_metric_catalog() {
+        let entries = metric_catalog().unwrap();
+
+        let names: HashSet<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names.len(), entries.len(), "metric names must be unique");
+
+        for name in [
+            "up",
+            "exporter_build_info",
+            "exporter_target_info",
+            "exporter_collector_missing_privilege",
+            "replica_master",
+            "perf_schema_consumer_enabled",
+        ] {
+            assert!(names.contains(metric_name(name).as_str()), "{name} missing");
+        }
+
+        assert!(entries.iter().all(|e| !e.help.is_empty()));
+
+        let up = entries
+            .iter()
+            .find(|e| e.name == metric_name("up"))
+            .unwrap();
+        assert_eq!(up.collector, None);
+
+        let master = entries
+            .iter()
+            .find(|e| e.name == metric_name("replica_master"))
+            .unwrap();
+        assert_eq!(master.collector, Some("replication"));
+    }
+}
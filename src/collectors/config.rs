@@ -1,8 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 #[derive(Clone, Debug, Default)]
 pub struct CollectorConfig {
     pub enabled_collectors: HashSet<String>,
+    /// `--external-label` pairs exported on `mariadb_exporter_target_info`
+    pub external_labels: BTreeMap<String, String>,
 }
 
 impl CollectorConfig {
@@ -19,6 +21,13 @@ impl CollectorConfig {
         self
     }
 
+    /// Set the labels of `mariadb_exporter_target_info` (none: the metric isn't exported)
+    #[must_use]
+    pub fn with_external_labels(mut self, labels: BTreeMap<String, String>) -> Self {
+        self.external_labels = labels;
+        self
+    }
+
     /// Check if a collector is enabled
     #[must_use]
     pub fn is_enabled(&self, name: &str) -> bool {
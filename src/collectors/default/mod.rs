@@ -1,8 +1,7 @@
-use crate::collectors::Collector;
-use anyhow::Result;
+use crate::collectors::{Collector, Registrar, fixture::StatusSnapshot};
+use anyhow::{Result, anyhow};
 use futures::future::BoxFuture;
 use futures::stream::{FuturesUnordered, StreamExt};
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use std::sync::Arc;
 use tracing::{debug, info_span, instrument, warn};
@@ -17,6 +16,9 @@ use status::StatusCollector;
 pub mod plugins;
 use plugins::PluginsCollector;
 
+pub mod time;
+use time::TimeCollector;
+
 /// `DefaultCollector` bundles lightweight always-on signals.
 #[derive(Clone, Default)]
 pub struct DefaultCollector {
@@ -31,6 +33,7 @@ impl DefaultCollector {
                 Arc::new(VersionCollector::new()),
                 Arc::new(StatusCollector::new()),
                 Arc::new(PluginsCollector::new()),
+                Arc::new(TimeCollector::new()),
             ],
         }
     }
@@ -47,20 +50,31 @@ impl Collector for DefaultCollector {
         err,
         fields(collector = "default")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        // Keep going on failure so one broken sub-collector doesn't hide the others' metrics.
+        let mut failed = Vec::new();
+
         for sub in &self.subs {
             let span = info_span!("collector.register_metrics", sub_collector = %sub.name());
-            let res = sub.register_metrics(registry);
-            match res {
+            let _guard = span.enter();
+
+            match sub.register_metrics(registry) {
                 Ok(()) => debug!(collector = sub.name(), "registered metrics"),
-                Err(ref e) => {
+                Err(e) => {
                     warn!(collector = sub.name(), error = %e, "failed to register metrics");
+                    failed.push(sub.name());
                 }
             }
-            res?;
-            drop(span);
         }
-        Ok(())
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "failed to register sub-collectors: {}",
+                failed.join(", ")
+            ))
+        }
     }
 
     #[instrument(skip(self, pool), level = "info", err, fields(collector = "default", otel.kind = "internal"))]
@@ -81,6 +95,14 @@ impl Collector for DefaultCollector {
         })
     }
 
+    fn collect_from_fixture(&self, snapshot: &StatusSnapshot) -> Result<()> {
+        for sub in &self.subs {
+            sub.collect_from_fixture(snapshot)?;
+        }
+
+        Ok(())
+    }
+
     fn enabled_by_default(&self) -> bool {
         true
     }
@@ -89,6 +111,7 @@ impl Collector for DefaultCollector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prometheus::Registry;
 
     #[test]
     fn test_default_collector_name() {
@@ -101,4 +124,26 @@ mod tests {
         let collector = DefaultCollector::new();
         assert!(collector.enabled_by_default());
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_register_metrics_continues_after_failure() {
+        let registry = Registry::new();
+
+        // Pre-register the version metrics so the version sub-collector hits duplicates
+        VersionCollector::new().register_metrics(&registry).unwrap();
+
+        let err = DefaultCollector::new()
+            .register_metrics(&registry)
+            .unwrap_err();
+        assert!(err.to_string().contains("version"));
+
+        // The status sub-collector after it must still be registered
+        let metrics = registry.gather();
+        assert!(
+            metrics
+                .iter()
+                .any(|m| m.name() == "mariadb_global_status_uptime_seconds")
+        );
+    }
 }
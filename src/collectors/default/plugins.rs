@@ -1,7 +1,7 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar, util::metric_name};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::{IntGauge, Registry};
+use prometheus::IntGauge;
 use sqlx::MySqlPool;
 use tracing::{info_span, instrument};
 use tracing_futures::Instrument as _;
@@ -23,13 +23,13 @@ impl PluginsCollector {
     /// Panics if metric names are invalid (should not occur with static names).
     pub fn new() -> Self {
         let audit_log_enabled = IntGauge::new(
-            "mariadb_audit_log_enabled",
+            metric_name("audit_log_enabled"),
             "Whether the audit_log plugin is active (1=enabled, 0=disabled)",
         )
         .expect("valid mariadb_audit_log_enabled metric");
 
         let userstat_enabled = IntGauge::new(
-            "mariadb_userstat_enabled",
+            metric_name("userstat_enabled"),
             "Whether user statistics are enabled (1=enabled, 0=disabled)",
         )
         .expect("valid mariadb_userstat_enabled metric");
@@ -58,7 +58,7 @@ impl Collector for PluginsCollector {
         err,
         fields(collector = "plugins")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.audit_log_enabled.clone()))?;
         registry.register(Box::new(self.userstat_enabled.clone()))?;
         Ok(())
@@ -0,0 +1,158 @@
+use crate::collectors::{Collector, Registrar, util::metric_name};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGauge, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+const TIME_QUERY: &str = "SELECT CAST(UNIX_TIMESTAMP() AS SIGNED),
+        CAST(TIME_TO_SEC(TIMEDIFF(NOW(), UTC_TIMESTAMP())) AS SIGNED),
+        CAST(@@system_time_zone AS CHAR),
+        CAST(@@time_zone AS CHAR)";
+
+/// Server clock and time zone (always-on; a single cheap query).
+///
+/// `mariadb_server_time_seconds` compared with `time()` in Prometheus shows clock skew
+/// between the exporter host and the server.
+#[derive(Clone)]
+pub struct TimeCollector {
+    server_time_seconds: IntGauge,
+    time_zone_offset_seconds: IntGauge,
+    time_zone_info: IntGaugeVec,
+}
+
+impl TimeCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new time collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        let server_time_seconds = IntGauge::new(
+            metric_name("server_time_seconds"),
+            "Server clock as a Unix timestamp (UNIX_TIMESTAMP())",
+        )
+        .expect("valid mariadb_server_time_seconds metric");
+
+        let time_zone_offset_seconds = IntGauge::new(
+            metric_name("global_variables_time_zone_offset_seconds"),
+            "Offset of the server time zone from UTC in seconds (NOW() - UTC_TIMESTAMP())",
+        )
+        .expect("valid mariadb_global_variables_time_zone_offset_seconds metric");
+
+        let time_zone_info = IntGaugeVec::new(
+            Opts::new(
+                metric_name("global_variables_time_zone_info"),
+                "Configured time zones (@@system_time_zone, @@time_zone), always 1",
+            ),
+            &["system_time_zone", "time_zone"],
+        )
+        .expect("valid mariadb_global_variables_time_zone_info metric");
+
+        Self {
+            server_time_seconds,
+            time_zone_offset_seconds,
+            time_zone_info,
+        }
+    }
+
+    fn apply(&self, now: i64, offset: i64, system_time_zone: &str, time_zone: &str) {
+        self.server_time_seconds.set(now);
+        self.time_zone_offset_seconds.set(offset);
+
+        // Reset so a changed time zone doesn't leave the old label set behind
+        self.time_zone_info.reset();
+        self.time_zone_info
+            .with_label_values(&[system_time_zone, time_zone])
+            .set(1);
+    }
+}
+
+impl Default for TimeCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for TimeCollector {
+    fn name(&self) -> &'static str {
+        "time"
+    }
+
+    #[instrument(skip(self, registry), level = "info", err, fields(collector = "time"))]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.server_time_seconds.clone()))?;
+        registry.register(Box::new(self.time_zone_offset_seconds.clone()))?;
+        registry.register(Box::new(self.time_zone_info.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "time", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = TIME_QUERY,
+                otel.kind = "client"
+            );
+
+            match sqlx::query_as::<_, (i64, Option<i64>, Option<String>, Option<String>)>(
+                TIME_QUERY,
+            )
+            .fetch_one(pool)
+            .instrument(span)
+            .await
+            {
+                Ok((now, offset, system_time_zone, time_zone)) => self.apply(
+                    now,
+                    offset.unwrap_or(0),
+                    system_time_zone.as_deref().unwrap_or(""),
+                    time_zone.as_deref().unwrap_or(""),
+                ),
+                Err(e) => debug!(error = %e, "failed to query server time"),
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::core::Collector as _;
+
+    #[test]
+    fn test_apply_replaces_time_zone_info() {
+        let collector = TimeCollector::new();
+
+        collector.apply(1_700_000_000, 3600, "CET", "SYSTEM");
+        assert_eq!(collector.server_time_seconds.get(), 1_700_000_000);
+        assert_eq!(collector.time_zone_offset_seconds.get(), 3600);
+
+        collector.apply(1_700_000_015, 0, "UTC", "+00:00");
+        let series: usize = collector
+            .time_zone_info
+            .collect()
+            .iter()
+            .map(|mf| mf.get_metric().len())
+            .sum();
+        assert_eq!(series, 1);
+        assert_eq!(
+            collector
+                .time_zone_info
+                .with_label_values(&["UTC", "+00:00"])
+                .get(),
+            1
+        );
+    }
+}
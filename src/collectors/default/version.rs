@@ -1,17 +1,37 @@
-use crate::collectors::{util::normalize_mariadb_version, Collector};
+use crate::collectors::{
+    util::{
+        ServerFork, metric_name, normalize_mariadb_version, set_mariadb_version, set_server_fork,
+    },
+    Collector, Registrar,
+};
 use anyhow::{Result, anyhow};
 use futures::future::BoxFuture;
-use prometheus::{IntGauge, IntGaugeVec, Opts, Registry};
+use prometheus::{IntGauge, IntGaugeVec, Opts};
 use sqlx::MySqlPool;
 use sysinfo::System;
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// Identity variables for `mariadb_server_info`. `server_uuid` only exists on `MySQL` and
+/// `gtid_domain_id` only on `MariaDB`; a variable the server lacks has no row and is exported
+/// empty. `SHOW GLOBAL VARIABLES` works on both, unlike `information_schema.GLOBAL_VARIABLES`,
+/// which `MySQL` 8.0 dropped.
+const SERVER_IDENTITY_QUERY: &str = "SHOW GLOBAL VARIABLES WHERE Variable_name IN ('server_id','server_uuid','gtid_domain_id')";
+
+/// Value of `name` in the `SERVER_IDENTITY_QUERY` rows, empty when the server lacks it.
+fn identity_variable<'a>(rows: &'a [(String, String)], name: &str) -> &'a str {
+    rows.iter()
+        .find(|(variable, _)| variable.eq_ignore_ascii_case(name))
+        .map_or("", |(_, value)| value.as_str())
+}
+
 /// Handles `MariaDB` version metrics
 #[derive(Clone)]
 pub struct VersionCollector {
     mariadb_version_info: IntGaugeVec,
     mariadb_version_num: IntGaugeVec,
+    mariadb_server_info: IntGaugeVec,
+    server_fork: IntGaugeVec,
     system_memory_total_bytes: IntGauge,
 }
 
@@ -31,7 +51,7 @@ impl VersionCollector {
     pub fn new() -> Self {
         let mariadb_version_info = IntGaugeVec::new(
             Opts::new(
-                "mariadb_version_info",
+                metric_name("version_info"),
                 "MariaDB version information with labels for version details.",
             ),
             &["version", "short_version"],
@@ -40,15 +60,33 @@ impl VersionCollector {
 
         let mariadb_version_num = IntGaugeVec::new(
             Opts::new(
-                "mariadb_version_num",
+                metric_name("version_num"),
                 "MariaDB version number formatted as major*10000 + minor*100 + patch",
             ),
             &["server"],
         )
         .expect("valid mariadb_version_num metric opts");
 
+        let mariadb_server_info = IntGaugeVec::new(
+            Opts::new(
+                metric_name("server_info"),
+                "MariaDB server identity (stable across hostname changes), value is always 1.",
+            ),
+            &["server_id", "server_uuid", "gtid_domain_id", "version"],
+        )
+        .expect("valid mariadb_server_info metric opts");
+
+        let server_fork = IntGaugeVec::new(
+            Opts::new(
+                metric_name("server_fork"),
+                "Server fork detected from VERSION() (mariadb or mysql), value is always 1.",
+            ),
+            &["fork"],
+        )
+        .expect("valid mariadb_server_fork metric opts");
+
         let system_memory_total_bytes = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_system_memory_total_bytes",
+            metric_name("exporter_system_memory_total_bytes"),
             "Total system memory in bytes",
         ))
         .expect("mariadb_exporter_system_memory_total_bytes");
@@ -61,6 +99,8 @@ impl VersionCollector {
         Self {
             mariadb_version_info,
             mariadb_version_num,
+            mariadb_server_info,
+            server_fork,
             system_memory_total_bytes,
         }
     }
@@ -103,6 +143,53 @@ impl VersionCollector {
         }
     }
 
+    #[instrument(skip(self, pool), level = "info", fields(db.system = "mysql", otel.kind = "client"))]
+    async fn collect_server_identity(&self, pool: &MySqlPool, short_version: &str) {
+        let span = info_span!(
+            "db.query",
+            db.operation = "SELECT",
+            db.statement = SERVER_IDENTITY_QUERY
+        );
+        let identity = sqlx::query_as::<_, (String, String)>(SERVER_IDENTITY_QUERY)
+            .fetch_all(pool)
+            .instrument(span)
+            .await;
+
+        match identity {
+            Ok(rows) => {
+                self.update_server_info(
+                    identity_variable(&rows, "server_id"),
+                    identity_variable(&rows, "server_uuid"),
+                    identity_variable(&rows, "gtid_domain_id"),
+                    short_version,
+                );
+            }
+            Err(e) => {
+                self.mariadb_server_info.reset();
+                debug!(error = %e, "failed to fetch server identity; skipping mariadb_server_info");
+            }
+        }
+    }
+
+    fn update_server_info(
+        &self,
+        server_id: &str,
+        server_uuid: &str,
+        gtid_domain_id: &str,
+        version: &str,
+    ) {
+        // Drop the previous identity so a promotion or server_id change leaves no dead series.
+        self.mariadb_server_info.reset();
+        self.mariadb_server_info
+            .with_label_values(&[server_id, server_uuid, gtid_domain_id, version])
+            .set(1);
+    }
+
+    fn update_server_fork(&self, fork: ServerFork) {
+        self.server_fork.reset();
+        self.server_fork.with_label_values(&[fork.as_str()]).set(1);
+    }
+
     fn normalize_version(version: &str) -> Result<(String, i64)> {
         let (normalized, num) = normalize_mariadb_version(version);
         if num == 0 && normalized == "0.0.0" {
@@ -142,9 +229,11 @@ impl Collector for VersionCollector {
         err,
         fields(collector = "version")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.mariadb_version_info.clone()))?;
         registry.register(Box::new(self.mariadb_version_num.clone()))?;
+        registry.register(Box::new(self.mariadb_server_info.clone()))?;
+        registry.register(Box::new(self.server_fork.clone()))?;
         registry.register(Box::new(self.system_memory_total_bytes.clone()))?;
         Ok(())
     }
@@ -165,6 +254,16 @@ impl Collector for VersionCollector {
                 .await?;
 
             let (short_version, version_num) = Self::normalize_version(&full_version)?;
+
+            // Single source of truth for version gating elsewhere, refreshed after upgrades.
+            if let Ok(num) = i32::try_from(version_num) {
+                set_mariadb_version(num);
+            }
+
+            let fork = ServerFork::from_version(&full_version);
+            set_server_fork(fork);
+            self.update_server_fork(fork);
+
             let server_label = self.get_server_info(pool).await?;
 
             self.update_version_metrics(
@@ -174,6 +273,8 @@ impl Collector for VersionCollector {
                 version_num,
             );
 
+            self.collect_server_identity(pool, &short_version).await;
+
             Ok(())
         })
     }
@@ -186,6 +287,7 @@ impl Collector for VersionCollector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prometheus::Registry;
 
     #[test]
     fn test_normalize_version() {
@@ -205,6 +307,18 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_normalize_version_debian_package() {
+        assert!(matches!(
+            VersionCollector::normalize_version("10.11.6-MariaDB-1:10.11.6+maria~ubu2204"),
+            Ok((ref normalized, num)) if normalized == "10.11.6" && num == 101_106
+        ));
+        assert!(matches!(
+            VersionCollector::normalize_version("1:10.11.6+maria~ubu2204"),
+            Ok((ref normalized, num)) if normalized == "10.11.6" && num == 101_106
+        ));
+    }
+
     #[test]
     fn test_normalize_version_single_component() {
         assert!(matches!(
@@ -220,6 +334,29 @@ mod tests {
         assert_eq!(collector.name(), "version");
     }
 
+    #[test]
+    fn test_server_fork_label() -> Result<()> {
+        let collector = VersionCollector::new();
+        let registry = Registry::new();
+
+        collector.register_metrics(&registry)?;
+
+        collector.update_server_fork(ServerFork::MariaDb);
+        collector.update_server_fork(ServerFork::MySql);
+
+        let metric_families = registry.gather();
+        let forks: Vec<&str> = metric_families
+            .iter()
+            .filter(|m| m.name() == "mariadb_server_fork")
+            .flat_map(|m| m.get_metric().iter())
+            .flat_map(|m| m.get_label().iter())
+            .map(prometheus::proto::LabelPair::value)
+            .collect();
+        assert_eq!(forks, vec!["mysql"]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_version_labels_reset_on_update() -> Result<()> {
         let collector = VersionCollector::new();
@@ -246,6 +383,15 @@ mod tests {
             .find(|m| m.name() == "mariadb_version_info")
             .ok_or_else(|| anyhow!("mariadb_version_info should exist"))?;
         assert_eq!(version_info.get_metric().len(), 1);
+        let live = version_info
+            .get_metric()
+            .first()
+            .ok_or_else(|| anyhow!("mariadb_version_info should have a series"))?;
+        assert!(
+            live.get_label()
+                .iter()
+                .any(|l| l.name() == "short_version" && l.value() == "10.6.1")
+        );
 
         let version_num = metric_families
             .iter()
@@ -255,4 +401,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_server_info_reset_on_update() -> Result<()> {
+        let collector = VersionCollector::new();
+        let registry = Registry::new();
+
+        collector.register_metrics(&registry)?;
+
+        collector.update_server_info("1", "", "0", "10.11.6");
+        collector.update_server_info("2", "", "0", "10.11.6");
+
+        let metric_families = registry.gather();
+        let server_info = metric_families
+            .iter()
+            .find(|m| m.name() == "mariadb_server_info")
+            .ok_or_else(|| anyhow!("mariadb_server_info should exist"))?;
+        assert_eq!(server_info.get_metric().len(), 1);
+
+        let live = server_info
+            .get_metric()
+            .first()
+            .ok_or_else(|| anyhow!("mariadb_server_info should have a series"))?;
+        assert!(
+            live.get_label()
+                .iter()
+                .any(|l| l.name() == "server_id" && l.value() == "2")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_identity_variable_defaults_to_empty() {
+        let mariadb = vec![
+            ("server_id".to_string(), "1".to_string()),
+            ("gtid_domain_id".to_string(), "0".to_string()),
+        ];
+        assert_eq!(identity_variable(&mariadb, "server_id"), "1");
+        assert_eq!(identity_variable(&mariadb, "server_uuid"), "");
+        assert_eq!(identity_variable(&mariadb, "gtid_domain_id"), "0");
+
+        // MySQL has no gtid_domain_id row
+        let mysql = vec![
+            ("server_id".to_string(), "2".to_string()),
+            (
+                "server_uuid".to_string(),
+                "3e11fa47-71ca-11e1-9e33-c80aa9429562".to_string(),
+            ),
+        ];
+        assert_eq!(identity_variable(&mysql, "server_id"), "2");
+        assert_eq!(
+            identity_variable(&mysql, "server_uuid"),
+            "3e11fa47-71ca-11e1-9e33-c80aa9429562"
+        );
+        assert_eq!(identity_variable(&mysql, "gtid_domain_id"), "");
+
+        // Variable names are matched case-insensitively
+        let upper = vec![("SERVER_ID".to_string(), "3".to_string())];
+        assert_eq!(identity_variable(&upper, "server_id"), "3");
+    }
 }
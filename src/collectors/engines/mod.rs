@@ -0,0 +1,175 @@
+use crate::collectors::{Collector, Registrar, util::metric_name};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Storage engine availability from `information_schema.ENGINES` (opt-in).
+///
+/// Useful after migrations to confirm which engines are still enabled and which is the default.
+#[derive(Clone)]
+pub struct EnginesCollector {
+    supported: IntGaugeVec,
+    transactions: IntGaugeVec,
+}
+
+impl EnginesCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new engines collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            supported: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("engine_supported"),
+                    "Whether the storage engine is available (1 for YES/DEFAULT), with the raw SUPPORT value",
+                ),
+                &["engine", "support"],
+            )
+            .expect("valid mariadb_engine_supported metric"),
+            transactions: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("engine_transactions"),
+                    "Whether the storage engine supports transactions (1/0)",
+                ),
+                &["engine"],
+            )
+            .expect("valid mariadb_engine_transactions metric"),
+        }
+    }
+
+    fn set_engine(&self, engine: &str, support: &str, transactions: Option<&str>) {
+        let available = matches!(support.to_ascii_uppercase().as_str(), "YES" | "DEFAULT");
+        self.supported
+            .with_label_values(&[engine, support])
+            .set(i64::from(available));
+
+        // NULL for engines the server lists but can't describe (e.g. not loaded)
+        if let Some(transactions) = transactions {
+            self.transactions
+                .with_label_values(&[engine])
+                .set(i64::from(transactions.eq_ignore_ascii_case("YES")));
+        }
+    }
+}
+
+impl Default for EnginesCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for EnginesCollector {
+    fn name(&self) -> &'static str {
+        "engines"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "engines")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.supported.clone()))?;
+        registry.register(Box::new(self.transactions.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "engines", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.supported.reset();
+            self.transactions.reset();
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement =
+                    "SELECT ENGINE, SUPPORT, TRANSACTIONS FROM information_schema.ENGINES",
+                otel.kind = "client"
+            );
+
+            let rows = match sqlx::query_as::<_, (String, String, Option<String>)>(
+                "SELECT ENGINE, SUPPORT, TRANSACTIONS FROM information_schema.ENGINES",
+            )
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+            {
+                Ok(rows) => rows,
+                Err(e) => {
+                    debug!(error = %e, "information_schema.ENGINES not readable; skipping");
+                    return Ok(());
+                }
+            };
+
+            for (engine, support, transactions) in rows {
+                self.set_engine(&engine, &support, transactions.as_deref());
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::core::Collector as _;
+
+    #[test]
+    fn test_set_engine() {
+        let collector = EnginesCollector::new();
+
+        collector.set_engine("InnoDB", "DEFAULT", Some("YES"));
+        collector.set_engine("MyISAM", "YES", Some("NO"));
+        collector.set_engine("FEDERATED", "NO", None);
+
+        assert_eq!(
+            collector
+                .supported
+                .with_label_values(&["InnoDB", "DEFAULT"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            collector
+                .supported
+                .with_label_values(&["MyISAM", "YES"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            collector
+                .supported
+                .with_label_values(&["FEDERATED", "NO"])
+                .get(),
+            0
+        );
+        assert_eq!(
+            collector.transactions.with_label_values(&["InnoDB"]).get(),
+            1
+        );
+        assert_eq!(
+            collector.transactions.with_label_values(&["MyISAM"]).get(),
+            0
+        );
+
+        // No transactions series for an engine with NULL TRANSACTIONS
+        let families = collector.transactions.collect();
+        let series: usize = families.iter().map(|mf| mf.get_metric().len()).sum();
+        assert_eq!(series, 2);
+    }
+}
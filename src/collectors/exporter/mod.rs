@@ -1,14 +1,15 @@
+mod pool;
 mod process;
 mod scraper;
 
+pub use pool::PoolCollector;
 pub use process::ProcessCollector;
-pub use scraper::{ScrapeTimer, ScraperCollector};
+pub use scraper::{InflightScrape, ScrapeTimer, ScraperCollector, error_reason, inflight_scrapes};
 
-use crate::collectors::Collector;
-use anyhow::Result;
+use crate::collectors::{Collector, Registrar};
+use anyhow::{Result, anyhow};
 use futures::future::BoxFuture;
 use futures::stream::{FuturesUnordered, StreamExt};
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use std::sync::Arc;
 use tracing::{debug, info_span, instrument, warn};
@@ -34,6 +35,7 @@ impl ExporterCollector {
         Self {
             subs: vec![
                 Arc::new(ProcessCollector::new()),
+                Arc::new(PoolCollector::new()),
                 Arc::clone(&scraper) as Arc<dyn Collector + Send + Sync>,
             ],
             scraper,
@@ -57,24 +59,31 @@ impl Collector for ExporterCollector {
         err,
         fields(collector = "exporter")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        // Keep going on failure so one broken sub-collector doesn't hide the others' metrics.
+        let mut failed = Vec::new();
+
         for sub in &self.subs {
             let span = info_span!("collector.register_metrics", sub_collector = %sub.name());
+            let _guard = span.enter();
 
-            let res = sub.register_metrics(registry);
-
-            match res {
+            match sub.register_metrics(registry) {
                 Ok(()) => debug!(collector = sub.name(), "registered exporter metrics"),
-                Err(ref e) => {
+                Err(e) => {
                     warn!(collector = sub.name(), error = %e, "failed to register exporter metrics");
+                    failed.push(sub.name());
                 }
             }
+        }
 
-            res?;
-
-            drop(span);
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "failed to register sub-collectors: {}",
+                failed.join(", ")
+            ))
         }
-        Ok(())
     }
 
     #[instrument(skip(self, pool), level = "info", err, fields(collector = "exporter", otel.kind = "internal"))]
@@ -103,12 +112,13 @@ impl Collector for ExporterCollector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prometheus::Registry;
 
     #[test]
     #[allow(clippy::unwrap_used)]
     fn test_exporter_collector_new() {
         let collector = ExporterCollector::new();
-        assert_eq!(collector.subs.len(), 2);
+        assert_eq!(collector.subs.len(), 3);
     }
 
     #[test]
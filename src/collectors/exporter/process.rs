@@ -1,7 +1,7 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar, util::metric_name};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::{Gauge, IntGauge, Opts, Registry};
+use prometheus::{Gauge, IntGauge, Opts};
 use sqlx::MySqlPool;
 use std::sync::{Arc, Mutex};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
@@ -41,37 +41,37 @@ impl ProcessCollector {
     /// Panics if metric creation fails.
     pub fn new() -> Self {
         let cpu_percent = Gauge::with_opts(Opts::new(
-            "mariadb_exporter_process_cpu_percent",
+            metric_name("exporter_process_cpu_percent"),
             "Current CPU usage percentage (matches ps %cpu, can exceed 100%)",
         ))
         .expect("mariadb_exporter_process_cpu_percent");
 
         let cpu_cores = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_process_cpu_cores",
+            metric_name("exporter_process_cpu_cores"),
             "Number of CPU cores available on the system",
         ))
         .expect("mariadb_exporter_process_cpu_cores");
 
         let resident_memory_bytes = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_process_resident_memory_bytes",
+            metric_name("exporter_process_resident_memory_bytes"),
             "Resident memory size in bytes (RSS)",
         ))
         .expect("mariadb_exporter_process_resident_memory_bytes");
 
         let virtual_memory_bytes = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_process_virtual_memory_bytes",
+            metric_name("exporter_process_virtual_memory_bytes"),
             "Virtual memory size in bytes (VSZ)",
         ))
         .expect("mariadb_exporter_process_virtual_memory_bytes");
 
         let open_fds = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_process_open_fds",
+            metric_name("exporter_process_open_fds"),
             "Number of open file descriptors",
         ))
         .expect("mariadb_exporter_process_open_fds");
 
         let start_time_seconds = Gauge::with_opts(Opts::new(
-            "mariadb_exporter_process_start_time_seconds",
+            metric_name("exporter_process_start_time_seconds"),
             "Start time of the process since unix epoch in seconds",
         ))
         .expect("mariadb_exporter_process_start_time_seconds");
@@ -177,7 +177,7 @@ impl Collector for ProcessCollector {
         "metrics.process"
     }
 
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.cpu_percent.clone()))?;
         registry.register(Box::new(self.cpu_cores.clone()))?;
         registry.register(Box::new(self.resident_memory_bytes.clone()))?;
@@ -203,6 +203,7 @@ impl Collector for ProcessCollector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prometheus::Registry;
 
     #[test]
     fn test_process_collector_new() {
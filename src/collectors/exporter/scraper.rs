@@ -1,25 +1,167 @@
+use crate::collectors::{
+    Registrar,
+    util::{is_access_denied, metric_name},
+};
 use anyhow::Result;
-use prometheus::{CounterVec, GaugeVec, HistogramVec, IntGauge, Opts, Registry};
-use std::sync::{Arc, RwLock};
+use prometheus::{
+    CounterVec, GaugeVec, Histogram, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+};
+use sqlx::mysql::MySqlDatabaseError;
+use std::collections::HashMap;
+use std::sync::{
+    Arc, RwLock,
+    atomic::{AtomicI64, Ordering},
+};
 use std::time::Instant;
 
+/// `reason` label values of `mariadb_exporter_collector_scrape_errors_total`.
+pub const REASON_CONNECTION: &str = "connection";
+pub const REASON_PERMISSION: &str = "permission";
+pub const REASON_TIMEOUT: &str = "timeout";
+pub const REASON_PARSE: &str = "parse";
+/// Anything not classified, including timers dropped without an outcome.
+pub const REASON_OTHER: &str = "other";
+
+/// `/metrics` requests being served right now, shared by every target's scraper.
+static INFLIGHT_SCRAPES: AtomicI64 = AtomicI64::new(0);
+
+/// Counts a `/metrics` request as in flight from `enter()` until dropped.
+pub struct InflightScrape(());
+
+impl InflightScrape {
+    #[must_use]
+    pub fn enter() -> Self {
+        INFLIGHT_SCRAPES.fetch_add(1, Ordering::Relaxed);
+        Self(())
+    }
+}
+
+impl Drop for InflightScrape {
+    fn drop(&mut self) {
+        INFLIGHT_SCRAPES.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Number of `/metrics` requests currently in flight.
+#[must_use]
+pub fn inflight_scrapes() -> i64 {
+    INFLIGHT_SCRAPES.load(Ordering::Relaxed)
+}
+
+/// Server errors meaning the query ran out of time (`ER_LOCK_WAIT_TIMEOUT`,
+/// `ER_STATEMENT_TIMEOUT`).
+const TIMEOUT_ERRORS: &[u16] = &[1205, 1969];
+
+/// Server errors refusing the connection itself (`ER_CON_COUNT_ERROR`, `ER_HOST_IS_BLOCKED`,
+/// `ER_TOO_MANY_USER_CONNECTIONS`).
+const CONNECTION_ERRORS: &[u16] = &[1040, 1129, 1203];
+
+/// Classify a collector error into one of the `REASON_*` labels, from the first cause that
+/// says something: missing grants, network or pool trouble, time limits, or unparsable values.
+#[must_use]
+pub fn error_reason(err: &anyhow::Error) -> &'static str {
+    err.chain()
+        .find_map(|cause| {
+            if let Some(e) = cause.downcast_ref::<sqlx::Error>() {
+                return sqlx_error_reason(e);
+            }
+            if let Some(e) = cause.downcast_ref::<std::io::Error>() {
+                return Some(io_error_reason(e));
+            }
+            if cause.is::<tokio::time::error::Elapsed>() {
+                return Some(REASON_TIMEOUT);
+            }
+            if cause.is::<std::num::ParseIntError>() || cause.is::<std::num::ParseFloatError>() {
+                return Some(REASON_PARSE);
+            }
+            None
+        })
+        .unwrap_or(REASON_OTHER)
+}
+
+fn sqlx_error_reason(err: &sqlx::Error) -> Option<&'static str> {
+    if is_access_denied(err) {
+        return Some(REASON_PERMISSION);
+    }
+
+    match err {
+        sqlx::Error::PoolTimedOut => Some(REASON_TIMEOUT),
+        sqlx::Error::PoolClosed | sqlx::Error::WorkerCrashed | sqlx::Error::Tls(_) => {
+            Some(REASON_CONNECTION)
+        }
+        sqlx::Error::Io(e) => Some(io_error_reason(e)),
+        sqlx::Error::Decode(_)
+        | sqlx::Error::ColumnDecode { .. }
+        | sqlx::Error::ColumnNotFound(_)
+        | sqlx::Error::ColumnIndexOutOfBounds { .. }
+        | sqlx::Error::TypeNotFound { .. }
+        | sqlx::Error::RowNotFound => Some(REASON_PARSE),
+        sqlx::Error::Database(e) => {
+            let number = e.try_downcast_ref::<MySqlDatabaseError>()?.number();
+            if TIMEOUT_ERRORS.contains(&number) {
+                Some(REASON_TIMEOUT)
+            } else if CONNECTION_ERRORS.contains(&number) {
+                Some(REASON_CONNECTION)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn io_error_reason(err: &std::io::Error) -> &'static str {
+    match err.kind() {
+        std::io::ErrorKind::TimedOut => REASON_TIMEOUT,
+        _ => REASON_CONNECTION,
+    }
+}
+
 #[derive(Clone)]
 pub struct ScraperCollector {
     scrape_duration_seconds: HistogramVec,
+    /// End-to-end `/metrics` request time, including encoding
+    total_scrape_duration_seconds: Histogram,
     scrape_errors_total: CounterVec,
+    slow_scrapes_total: IntCounterVec,
+    permission_errors_total: IntCounterVec,
     last_scrape_timestamp: GaugeVec,
     last_scrape_success: GaugeVec,
+    seconds_since_last_success: GaugeVec,
     
     metrics_total: IntGauge,
+    collector_metrics: IntGaugeVec,
     scrapes_total: IntGauge,
+    inflight_scrapes: IntGauge,
     
     state: Arc<RwLock<ScraperState>>,
 }
 
-#[derive(Default)]
 struct ScraperState {
     total_scrapes: i64,
     total_metrics: i64,
+    /// Unix timestamp the scraper was created, used as the baseline before a first success
+    started: f64,
+    /// Unix timestamp of the last successful scrape per collector
+    last_success: HashMap<String, f64>,
+}
+
+impl Default for ScraperState {
+    fn default() -> Self {
+        Self {
+            total_scrapes: 0,
+            total_metrics: 0,
+            started: unix_now(),
+            last_success: HashMap::new(),
+        }
+    }
+}
+
+fn unix_now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
 }
 
 impl Default for ScraperCollector {
@@ -30,7 +172,7 @@ impl Default for ScraperCollector {
 
 impl ScraperCollector {
     #[must_use]
-    #[allow(clippy::expect_used)]
+    #[allow(clippy::expect_used, clippy::too_many_lines)]
     ///
     /// # Panics
     ///
@@ -38,7 +180,7 @@ impl ScraperCollector {
     pub fn new() -> Self {
         let scrape_duration_seconds = HistogramVec::new(
             prometheus::HistogramOpts::new(
-                "mariadb_exporter_collector_scrape_duration_seconds",
+                metric_name("exporter_collector_scrape_duration_seconds"),
                 "Time spent scraping each collector in seconds",
             )
             .buckets(vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0]),
@@ -46,18 +188,45 @@ impl ScraperCollector {
         )
         .expect("mariadb_exporter_collector_scrape_duration_seconds");
 
+        let total_scrape_duration_seconds = Histogram::with_opts(
+            prometheus::HistogramOpts::new(
+                metric_name("exporter_scrape_duration_seconds"),
+                "Time spent serving a whole /metrics request in seconds, including encoding",
+            )
+            .buckets(vec![0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]),
+        )
+        .expect("mariadb_exporter_scrape_duration_seconds");
+
         let scrape_errors_total = CounterVec::new(
             Opts::new(
-                "mariadb_exporter_collector_scrape_errors_total",
-                "Total number of scrape errors per collector",
+                metric_name("exporter_collector_scrape_errors_total"),
+                "Total number of scrape errors per collector, by reason (connection, permission, timeout, parse, other)",
             ),
-            &["collector"],
+            &["collector", "reason"],
         )
         .expect("mariadb_exporter_collector_scrape_errors_total");
 
+        let slow_scrapes_total = IntCounterVec::new(
+            Opts::new(
+                metric_name("exporter_slow_collector_scrapes_total"),
+                "Scrapes where the collector exceeded --slow-collector-threshold",
+            ),
+            &["collector"],
+        )
+        .expect("mariadb_exporter_slow_collector_scrapes_total");
+
+        let permission_errors_total = IntCounterVec::new(
+            Opts::new(
+                metric_name("exporter_collector_permission_errors_total"),
+                "Scrapes where the collector was denied access and skipped its metrics",
+            ),
+            &["collector"],
+        )
+        .expect("mariadb_exporter_collector_permission_errors_total");
+
         let last_scrape_timestamp = GaugeVec::new(
             Opts::new(
-                "mariadb_exporter_collector_last_scrape_timestamp_seconds",
+                metric_name("exporter_collector_last_scrape_timestamp_seconds"),
                 "Unix timestamp of the last scrape attempt per collector",
             ),
             &["collector"],
@@ -66,32 +235,62 @@ impl ScraperCollector {
 
         let last_scrape_success = GaugeVec::new(
             Opts::new(
-                "mariadb_exporter_collector_last_scrape_success",
+                metric_name("exporter_collector_last_scrape_success"),
                 "Whether the last scrape was successful (1=success, 0=failure)",
             ),
             &["collector"],
         )
         .expect("mariadb_exporter_collector_last_scrape_success");
 
+        let seconds_since_last_success = GaugeVec::new(
+            Opts::new(
+                metric_name("exporter_collector_seconds_since_last_success"),
+                "Seconds since the collector last scraped successfully (since exporter start if never)",
+            ),
+            &["collector"],
+        )
+        .expect("mariadb_exporter_collector_seconds_since_last_success");
+
         let metrics_total = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_metrics_total",
+            metric_name("exporter_metrics_total"),
             "Total number of metrics currently exported (for cardinality monitoring)",
         ))
         .expect("mariadb_exporter_metrics_total");
 
+        let collector_metrics = IntGaugeVec::new(
+            Opts::new(
+                metric_name("exporter_collector_metrics"),
+                "Number of series exported per collector (for cardinality monitoring)",
+            ),
+            &["collector"],
+        )
+        .expect("mariadb_exporter_collector_metrics");
+
         let scrapes_total = IntGauge::with_opts(Opts::new(
-            "mariadb_exporter_scrapes_total",
+            metric_name("exporter_scrapes_total"),
             "Total number of scrapes performed since start",
         ))
         .expect("mariadb_exporter_scrapes_total");
 
+        let inflight_scrapes = IntGauge::with_opts(Opts::new(
+            metric_name("exporter_inflight_scrapes"),
+            "Number of /metrics requests being served concurrently, this one included",
+        ))
+        .expect("mariadb_exporter_inflight_scrapes");
+
         Self {
             scrape_duration_seconds,
+            total_scrape_duration_seconds,
             scrape_errors_total,
+            slow_scrapes_total,
+            permission_errors_total,
             last_scrape_timestamp,
             last_scrape_success,
+            seconds_since_last_success,
             metrics_total,
+            collector_metrics,
             scrapes_total,
+            inflight_scrapes,
             state: Arc::new(RwLock::new(ScraperState::default())),
         }
     }
@@ -102,10 +301,17 @@ impl ScraperCollector {
             collector_name: collector_name.to_string(),
             start: Instant::now(),
             scraper: self.clone(),
-            recorded: false,
+            done: false,
         }
     }
 
+    /// Record the duration of a whole `/metrics` request.
+    ///
+    /// Observed after the response body is encoded, so it shows up on the next scrape.
+    pub fn observe_scrape_duration(&self, seconds: f64) {
+        self.total_scrape_duration_seconds.observe(seconds);
+    }
+
     pub fn update_metrics_count(&self, count: i64) {
         self.metrics_total.set(count);
         let mut state = match self.state.write() {
@@ -118,6 +324,24 @@ impl ScraperCollector {
         state.total_metrics = count;
     }
 
+    pub fn record_slow_scrape(&self, collector_name: &str) {
+        self.slow_scrapes_total
+            .with_label_values(&[collector_name])
+            .inc();
+    }
+
+    pub fn record_permission_error(&self, collector_name: &str) {
+        self.permission_errors_total
+            .with_label_values(&[collector_name])
+            .inc();
+    }
+
+    pub fn update_collector_metrics_count(&self, collector_name: &str, count: i64) {
+        self.collector_metrics
+            .with_label_values(&[collector_name])
+            .set(count);
+    }
+
     pub fn increment_scrapes(&self) {
         let mut state = match self.state.write() {
             Ok(guard) => guard,
@@ -128,13 +352,38 @@ impl ScraperCollector {
         };
         state.total_scrapes += 1;
         self.scrapes_total.set(state.total_scrapes);
+        self.inflight_scrapes.set(inflight_scrapes());
+    }
+
+    /// Update the success age for a collector; `success` marks this attempt as the new baseline.
+    fn update_success_age(&self, collector_name: &str, timestamp: f64, success: bool) {
+        let mut state = match self.state.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                tracing::warn!("ScraperState write lock was poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+
+        let age = if success {
+            state.last_success.insert(collector_name.to_string(), timestamp);
+            0.0
+        } else {
+            let since = state
+                .last_success
+                .get(collector_name)
+                .copied()
+                .unwrap_or(state.started);
+            (timestamp - since).max(0.0)
+        };
+
+        self.seconds_since_last_success
+            .with_label_values(&[collector_name])
+            .set(age);
     }
 
     fn record_success(&self, collector_name: &str, duration: f64) {
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs_f64();
+        let timestamp = unix_now();
 
         self.scrape_duration_seconds
             .with_label_values(&[collector_name])
@@ -147,16 +396,15 @@ impl ScraperCollector {
         self.last_scrape_success
             .with_label_values(&[collector_name])
             .set(1.0);
+
+        self.update_success_age(collector_name, timestamp, true);
     }
 
-    fn record_error(&self, collector_name: &str) {
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs_f64();
+    fn record_error(&self, collector_name: &str, reason: &str) {
+        let timestamp = unix_now();
 
         self.scrape_errors_total
-            .with_label_values(&[collector_name])
+            .with_label_values(&[collector_name, reason])
             .inc();
 
         self.last_scrape_timestamp
@@ -166,19 +414,27 @@ impl ScraperCollector {
         self.last_scrape_success
             .with_label_values(&[collector_name])
             .set(0.0);
+
+        self.update_success_age(collector_name, timestamp, false);
     }
 
     ///
     /// # Errors
     ///
     /// Returns an error if metric registration fails.
-    pub fn register(&self, registry: &Registry) -> Result<()> {
+    pub fn register(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.scrape_duration_seconds.clone()))?;
+        registry.register(Box::new(self.total_scrape_duration_seconds.clone()))?;
         registry.register(Box::new(self.scrape_errors_total.clone()))?;
+        registry.register(Box::new(self.slow_scrapes_total.clone()))?;
+        registry.register(Box::new(self.permission_errors_total.clone()))?;
         registry.register(Box::new(self.last_scrape_timestamp.clone()))?;
         registry.register(Box::new(self.last_scrape_success.clone()))?;
+        registry.register(Box::new(self.seconds_since_last_success.clone()))?;
         registry.register(Box::new(self.metrics_total.clone()))?;
+        registry.register(Box::new(self.collector_metrics.clone()))?;
         registry.register(Box::new(self.scrapes_total.clone()))?;
+        registry.register(Box::new(self.inflight_scrapes.clone()))?;
         Ok(())
     }
 }
@@ -188,7 +444,7 @@ impl crate::collectors::Collector for ScraperCollector {
         "scraper"
     }
 
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         self.register(registry)
     }
 
@@ -205,35 +461,43 @@ pub struct ScrapeTimer {
     collector_name: String,
     start: Instant,
     scraper: ScraperCollector,
-    recorded: bool,
+    /// Set by `success()`/`error()`; an unfinished timer is counted as an error on drop
+    done: bool,
 }
 
 impl ScrapeTimer {
     pub fn success(mut self) {
-        self.recorded = true;
+        self.done = true;
         let duration = self.start.elapsed().as_secs_f64();
         self.scraper.record_success(&self.collector_name, duration);
     }
 
-    pub fn error(mut self) {
-        self.recorded = true;
-        self.scraper.record_error(&self.collector_name);
+    /// Record a failed scrape with reason `other`.
+    pub fn error(self) {
+        self.error_with_reason(REASON_OTHER);
+    }
+
+    /// Record a failed scrape, labelled with one of the `REASON_*` values (see `error_reason`).
+    pub fn error_with_reason(mut self, reason: &str) {
+        self.done = true;
+        self.scraper.record_error(&self.collector_name, reason);
     }
 }
 
 impl Drop for ScrapeTimer {
     fn drop(&mut self) {
-        if self.recorded {
+        if self.done {
             return;
         }
-        let duration = self.start.elapsed().as_secs_f64();
-        self.scraper.record_success(&self.collector_name, duration);
+        // Dropped without an outcome (panic, cancelled future, early return): not a success.
+        self.scraper.record_error(&self.collector_name, REASON_OTHER);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prometheus::Registry;
     use std::thread;
     use std::time::Duration;
 
@@ -253,6 +517,16 @@ mod tests {
         assert!(scraper.register(&registry).is_ok());
     }
 
+    #[test]
+    fn test_observe_scrape_duration() {
+        let scraper = ScraperCollector::new();
+        scraper.observe_scrape_duration(0.2);
+        scraper.observe_scrape_duration(0.3);
+
+        assert_eq!(scraper.total_scrape_duration_seconds.get_sample_count(), 2);
+        assert!((scraper.total_scrape_duration_seconds.get_sample_sum() - 0.5).abs() < 1e-9);
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)]
     #[allow(clippy::expect_used)]
@@ -298,6 +572,135 @@ mod tests {
         assert!(!error_metric.get_metric().is_empty());
     }
 
+    #[test]
+    fn test_seconds_since_last_success() {
+        let scraper = ScraperCollector::new();
+        let age = |s: &ScraperCollector| {
+            s.seconds_since_last_success
+                .with_label_values(&["test_age"])
+                .get()
+        };
+
+        scraper.record_success("test_age", 0.1);
+        assert!(age(&scraper).abs() < f64::EPSILON);
+
+        // Pretend the last success was a minute ago
+        if let Ok(mut state) = scraper.state.write() {
+            state.last_success.insert("test_age".to_string(), unix_now() - 60.0);
+        }
+
+        scraper.record_error("test_age", REASON_TIMEOUT);
+        assert!(age(&scraper) >= 60.0);
+
+        // The attempt timestamp moves on errors, the success baseline does not
+        scraper.record_error("test_age", REASON_TIMEOUT);
+        assert!(age(&scraper) >= 60.0);
+
+        scraper.record_success("test_age", 0.1);
+        assert!(age(&scraper).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_seconds_since_last_success_never_succeeded() {
+        let scraper = ScraperCollector::new();
+        if let Ok(mut state) = scraper.state.write() {
+            state.started = unix_now() - 30.0;
+        }
+
+        scraper.record_error("test_never", REASON_CONNECTION);
+        assert!(
+            scraper
+                .seconds_since_last_success
+                .with_label_values(&["test_never"])
+                .get()
+                >= 30.0
+        );
+    }
+
+    #[test]
+    fn test_scrape_timer_drop_records_error() {
+        let scraper = ScraperCollector::new();
+
+        {
+            let _timer = scraper.start_scrape("test_dropped");
+        }
+
+        assert!(
+            (scraper
+                .scrape_errors_total
+                .with_label_values(&["test_dropped", REASON_OTHER])
+                .get()
+                - 1.0)
+                .abs()
+                < f64::EPSILON
+        );
+        assert!(
+            scraper
+                .last_scrape_success
+                .with_label_values(&["test_dropped"])
+                .get()
+                .abs()
+                < f64::EPSILON
+        );
+        assert_eq!(
+            scraper
+                .scrape_duration_seconds
+                .with_label_values(&["test_dropped"])
+                .get_sample_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_scrape_timer_error_with_reason() {
+        let scraper = ScraperCollector::new();
+
+        scraper
+            .start_scrape("test_reason")
+            .error_with_reason(REASON_PERMISSION);
+        scraper.start_scrape("test_reason").error();
+
+        for reason in [REASON_PERMISSION, REASON_OTHER] {
+            assert!(
+                (scraper
+                    .scrape_errors_total
+                    .with_label_values(&["test_reason", reason])
+                    .get()
+                    - 1.0)
+                    .abs()
+                    < f64::EPSILON,
+                "expected one {reason} error"
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_error_reason() {
+        let io = |kind| anyhow::Error::new(sqlx::Error::Io(std::io::Error::from(kind)));
+
+        assert_eq!(error_reason(&io(std::io::ErrorKind::ConnectionRefused)), REASON_CONNECTION);
+        assert_eq!(error_reason(&io(std::io::ErrorKind::TimedOut)), REASON_TIMEOUT);
+        assert_eq!(
+            error_reason(&anyhow::Error::new(sqlx::Error::PoolTimedOut)),
+            REASON_TIMEOUT
+        );
+        assert_eq!(
+            error_reason(&anyhow::Error::new(sqlx::Error::ColumnNotFound("x".into()))),
+            REASON_PARSE
+        );
+        assert_eq!(
+            error_reason(&"abc".parse::<i64>().map_err(anyhow::Error::new).unwrap_err()),
+            REASON_PARSE
+        );
+
+        // Context on top doesn't hide the cause
+        let wrapped = anyhow::Error::new(sqlx::Error::PoolClosed).context("SHOW GLOBAL STATUS");
+        assert_eq!(error_reason(&wrapped), REASON_CONNECTION);
+
+        assert_eq!(error_reason(&anyhow::anyhow!("unexpected")), REASON_OTHER);
+    }
+
     #[test]
     fn test_update_metrics_count() {
         let scraper = ScraperCollector::new();
@@ -305,6 +708,51 @@ mod tests {
         assert_eq!(scraper.metrics_total.get(), 42);
     }
 
+    #[test]
+    fn test_update_collector_metrics_count() {
+        let scraper = ScraperCollector::new();
+        scraper.update_collector_metrics_count("default", 120);
+        scraper.update_collector_metrics_count("innodb", 7);
+        assert_eq!(
+            scraper
+                .collector_metrics
+                .with_label_values(&["default"])
+                .get(),
+            120
+        );
+        assert_eq!(
+            scraper.collector_metrics.with_label_values(&["innodb"]).get(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_record_slow_scrape() {
+        let scraper = ScraperCollector::new();
+        scraper.record_slow_scrape("statements");
+        scraper.record_slow_scrape("statements");
+        assert_eq!(
+            scraper
+                .slow_scrapes_total
+                .with_label_values(&["statements"])
+                .get(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_record_permission_error() {
+        let scraper = ScraperCollector::new();
+        scraper.record_permission_error("statements");
+        assert_eq!(
+            scraper
+                .permission_errors_total
+                .with_label_values(&["statements"])
+                .get(),
+            1
+        );
+    }
+
     #[test]
     fn test_increment_scrapes() {
         let scraper = ScraperCollector::new();
@@ -314,6 +762,19 @@ mod tests {
         assert_eq!(scraper.scrapes_total.get(), 2);
     }
 
+    #[test]
+    fn test_inflight_scrapes() {
+        let scraper = ScraperCollector::new();
+
+        // Other tests may have requests in flight too, hence the lower bounds
+        let _first = InflightScrape::enter();
+        let _second = InflightScrape::enter();
+        assert!(inflight_scrapes() >= 2);
+
+        scraper.increment_scrapes();
+        assert!(scraper.inflight_scrapes.get() >= 2);
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)]
     #[allow(clippy::expect_used)]
@@ -0,0 +1,216 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{PICO_TO_SECONDS, is_access_denied, metric_name, series_limit},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{GaugeVec, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Default number of event names, overridden by `--max-series-per-collector`.
+const TOP_EVENT_NAMES: usize = 10;
+
+/// Busiest event names by total wait time; the limit bounds cardinality.
+const FILE_IO_QUERY: &str = "SELECT EVENT_NAME,
+        CAST(SUM_TIMER_READ AS UNSIGNED),
+        CAST(SUM_TIMER_WRITE AS UNSIGNED),
+        CAST(SUM_NUMBER_OF_BYTES_READ AS UNSIGNED),
+        CAST(SUM_NUMBER_OF_BYTES_WRITE AS UNSIGNED)
+     FROM performance_schema.file_summary_by_event_name
+     WHERE COUNT_STAR > 0
+     ORDER BY SUM_TIMER_WAIT DESC
+     LIMIT ?";
+
+/// One row of `file_summary_by_event_name`: timers in picoseconds, sizes in bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileIoRow {
+    event_name: String,
+    read_ps: u64,
+    write_ps: u64,
+    read_bytes: u64,
+    write_bytes: u64,
+}
+
+/// File I/O latency and volume from `performance_schema.file_summary_by_event_name` (opt-in).
+///
+/// Disk latency shows up here per file class (`wait/io/file/innodb/innodb_data_file`,
+/// `wait/io/file/sql/binlog`, ...), limited to the busiest event names.
+#[derive(Clone)]
+pub struct FileIoCollector {
+    io_seconds: GaugeVec,
+    io_bytes: IntGaugeVec,
+}
+
+impl FileIoCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new file I/O collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            io_seconds: GaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_file_io_seconds_total"),
+                    "Time spent in file reads and writes per event name, picoseconds converted to seconds",
+                ),
+                &["event_name", "operation"],
+            )
+            .expect("valid mariadb_perf_schema_file_io_seconds_total metric"),
+            io_bytes: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_file_io_bytes_total"),
+                    "Bytes read and written per file I/O event name",
+                ),
+                &["event_name", "operation"],
+            )
+            .expect("valid mariadb_perf_schema_file_io_bytes_total metric"),
+        }
+    }
+
+    fn set_row(&self, row: &FileIoRow) {
+        let event = row.event_name.as_str();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            self.io_seconds
+                .with_label_values(&[event, "read"])
+                .set(row.read_ps as f64 / PICO_TO_SECONDS);
+            self.io_seconds
+                .with_label_values(&[event, "write"])
+                .set(row.write_ps as f64 / PICO_TO_SECONDS);
+        }
+
+        self.io_bytes
+            .with_label_values(&[event, "read"])
+            .set(i64::try_from(row.read_bytes).unwrap_or(i64::MAX));
+        self.io_bytes
+            .with_label_values(&[event, "write"])
+            .set(i64::try_from(row.write_bytes).unwrap_or(i64::MAX));
+    }
+}
+
+impl Default for FileIoCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for FileIoCollector {
+    fn name(&self) -> &'static str {
+        "file_io"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "file_io")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.io_seconds.clone()))?;
+        registry.register(Box::new(self.io_bytes.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "file_io", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // The top event names change between scrapes; drop the ones that fell out
+            self.io_seconds.reset();
+            self.io_bytes.reset();
+
+            // Confirm table exists (Performance Schema might be off)
+            let exists_span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "check file_summary_by_event_name table",
+                otel.kind = "client"
+            );
+
+            let has_table = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema='performance_schema' AND table_name='file_summary_by_event_name'",
+            )
+            .fetch_one(pool)
+            .instrument(exists_span)
+            .await
+            .unwrap_or(0)
+                > 0;
+
+            if !has_table {
+                debug!("file_summary_by_event_name not available; skipping collection");
+                return Ok(());
+            }
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "top file I/O event names",
+                otel.kind = "client"
+            );
+
+            let rows = match sqlx::query_as::<_, (String, u64, u64, u64, u64)>(FILE_IO_QUERY)
+                .bind(u64::try_from(series_limit(TOP_EVENT_NAMES)).unwrap_or(u64::MAX))
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => {
+                    debug!(error = %e, "file_summary_by_event_name query failed; skipping");
+                    return Ok(());
+                }
+            };
+
+            for (event_name, read_ps, write_ps, read_bytes, write_bytes) in rows {
+                self.set_row(&FileIoRow {
+                    event_name,
+                    read_ps,
+                    write_ps,
+                    read_bytes,
+                    write_bytes,
+                });
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_row_converts_units() {
+        let collector = FileIoCollector::new();
+
+        collector.set_row(&FileIoRow {
+            event_name: "wait/io/file/innodb/innodb_data_file".to_string(),
+            read_ps: 2_500_000_000_000,
+            write_ps: 500_000_000,
+            read_bytes: 16_384,
+            write_bytes: u64::MAX,
+        });
+
+        let event = "wait/io/file/innodb/innodb_data_file";
+        let seconds = |op: &str| collector.io_seconds.with_label_values(&[event, op]).get();
+        let bytes = |op: &str| collector.io_bytes.with_label_values(&[event, op]).get();
+
+        assert!((seconds("read") - 2.5).abs() < f64::EPSILON);
+        assert!((seconds("write") - 0.0005).abs() < f64::EPSILON);
+        assert_eq!(bytes("read"), 16_384);
+        assert_eq!(bytes("write"), i64::MAX);
+    }
+}
@@ -0,0 +1,138 @@
+//! Captured server state for exercising collectors without a database, the replay path
+//! behind `Collector::collect_from_fixture`.
+//!
+//! A fixture is a directory with the output of the queries the status-based collectors run,
+//! as written by `scripts/capture-fixture.sh`:
+//!
+//! - `global_status.tsv`: `information_schema.global_status`, one `NAME<TAB>VALUE` per line
+//! - `global_variables.tsv`: `information_schema.global_variables`, same format
+//! - `innodb_status.txt`: the `Status` column of `SHOW ENGINE INNODB STATUS`
+//!
+//! Missing files leave their part of the snapshot empty.
+
+use anyhow::{Context, Result};
+use std::{collections::HashMap, fs, io::ErrorKind, path::Path};
+
+/// Query results a collector would otherwise fetch from the server.
+#[derive(Clone, Debug, Default)]
+pub struct StatusSnapshot {
+    /// `information_schema.global_status` rows, names as the server reports them
+    pub global_status: Vec<(String, String)>,
+    /// `information_schema.global_variables` rows, names as the server reports them
+    pub global_variables: Vec<(String, String)>,
+    /// `SHOW ENGINE INNODB STATUS` text
+    pub innodb_status: Option<String>,
+}
+
+impl StatusSnapshot {
+    /// Load a fixture directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a fixture file exists but can't be read
+    pub fn from_dir(dir: &Path) -> Result<Self> {
+        Ok(Self {
+            global_status: read_optional(&dir.join("global_status.tsv"))?
+                .as_deref()
+                .map(parse_rows)
+                .unwrap_or_default(),
+            global_variables: read_optional(&dir.join("global_variables.tsv"))?
+                .as_deref()
+                .map(parse_rows)
+                .unwrap_or_default(),
+            innodb_status: read_optional(&dir.join("innodb_status.txt"))?,
+        })
+    }
+
+    /// Global status by uppercased name, as `information_schema.global_status` returns it.
+    #[must_use]
+    pub fn status_map(&self) -> HashMap<String, String> {
+        self.global_status
+            .iter()
+            .map(|(name, value)| (name.to_ascii_uppercase(), value.clone()))
+            .collect()
+    }
+
+    /// Global variables by lowercased name, as the collectors look them up.
+    #[must_use]
+    pub fn variables_map(&self) -> HashMap<String, String> {
+        self.global_variables
+            .iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), value.clone()))
+            .collect()
+    }
+}
+
+/// Parse `NAME<TAB>VALUE` lines (`mariadb --batch --skip-column-names`); blank lines and
+/// `#` comments are skipped, and a line without a tab is a name with an empty value.
+#[must_use]
+pub fn parse_rows(text: &str) -> Vec<(String, String)> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, value) = line.split_once('\t').unwrap_or((line, ""));
+            (name.trim().to_string(), value.to_string())
+        })
+        .collect()
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("failed to read fixture {}", path.display())),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rows() {
+        let rows = parse_rows("# captured\nUptime\t3600\n\nSsl_cipher\t\nRpl_status\n");
+
+        assert_eq!(
+            rows,
+            vec![
+                ("Uptime".to_string(), "3600".to_string()),
+                ("Ssl_cipher".to_string(), String::new()),
+                ("Rpl_status".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_maps_normalize_names() {
+        let snapshot = StatusSnapshot {
+            global_status: vec![("Threads_connected".to_string(), "5".to_string())],
+            global_variables: vec![("MAX_CONNECTIONS".to_string(), "151".to_string())],
+            innodb_status: None,
+        };
+
+        assert_eq!(
+            snapshot
+                .status_map()
+                .get("THREADS_CONNECTED")
+                .map(String::as_str),
+            Some("5")
+        );
+        assert_eq!(
+            snapshot
+                .variables_map()
+                .get("max_connections")
+                .map(String::as_str),
+            Some("151")
+        );
+    }
+
+    #[test]
+    fn test_from_dir_missing_files_are_empty() {
+        let snapshot = StatusSnapshot::from_dir(Path::new("/nonexistent/fixture")).unwrap();
+
+        assert!(snapshot.global_status.is_empty());
+        assert!(snapshot.global_variables.is_empty());
+        assert!(snapshot.innodb_status.is_none());
+    }
+}
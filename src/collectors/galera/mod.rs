@@ -0,0 +1,67 @@
+use crate::collectors::{Collector, Registrar};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use sqlx::MySqlPool;
+use tracing::instrument;
+
+pub mod wsrep;
+
+use wsrep::WsrepStatusCollector;
+
+/// Galera collector (opt-in). Collects `wsrep_*` status from Galera cluster nodes.
+#[derive(Clone)]
+pub struct GaleraCollector {
+    wsrep: WsrepStatusCollector,
+}
+
+impl GaleraCollector {
+    #[must_use]
+    /// Create a new Galera collector.
+    pub fn new() -> Self {
+        Self {
+            wsrep: WsrepStatusCollector::new(),
+        }
+    }
+}
+
+impl Default for GaleraCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for GaleraCollector {
+    fn name(&self) -> &'static str {
+        "galera"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "galera")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.wsrep.cluster_size().clone()))?;
+        registry.register(Box::new(self.wsrep.ready().clone()))?;
+        registry.register(Box::new(self.wsrep.local_state().clone()))?;
+        registry.register(Box::new(self.wsrep.flow_control_paused_seconds().clone()))?;
+        registry.register(Box::new(self.wsrep.evs_delayed_nodes().clone()))?;
+        registry.register(Box::new(self.wsrep.evs_repl_latency_seconds().clone()))?;
+        registry.register(Box::new(self.wsrep.local_cert_failures().clone()))?;
+        registry.register(Box::new(self.wsrep.local_bf_aborts().clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "galera", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.wsrep.collect(pool).await?;
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
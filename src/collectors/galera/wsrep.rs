@@ -0,0 +1,345 @@
+use crate::collectors::{
+    i64_to_f64,
+    util::{counter_delta, metric_name},
+};
+use anyhow::Result;
+use prometheus::{Counter, GaugeVec, IntCounter, IntGauge, Opts};
+use sqlx::MySqlPool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, Ordering};
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+const NANOS_PER_SECOND: f64 = 1_000_000_000.0;
+
+/// Order of the values in `wsrep_evs_repl_latency` (`min/avg/max/stddev/sample_size`).
+const REPL_LATENCY_STATS: [&str; 4] = ["min", "avg", "max", "stddev"];
+
+/// Collector for Galera `wsrep_*` status variables.
+///
+/// Flow control pauses are the main Galera health signal: a node that can't keep up
+/// pauses replication for the whole cluster. Servers without the wsrep provider return
+/// no `wsrep_%` rows and leave these metrics empty.
+#[derive(Clone)]
+pub struct WsrepStatusCollector {
+    cluster_size: IntGauge,
+    ready: IntGauge,
+    local_state: IntGauge,
+    flow_control_paused_seconds: Counter,
+    evs_delayed_nodes: IntGauge,
+    evs_repl_latency_seconds: GaugeVec,
+    local_cert_failures: IntCounter,
+    local_bf_aborts: IntCounter,
+    // Last raw server values, to turn cumulative status into monotonic counters
+    flow_control_paused_ns_last: Arc<AtomicI64>,
+    local_cert_failures_last: Arc<AtomicI64>,
+    local_bf_aborts_last: Arc<AtomicI64>,
+}
+
+impl WsrepStatusCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new wsrep status collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        let g = |name: &str, help: &str| IntGauge::new(name, help).expect("valid wsrep metric");
+        let c = |name: &str, help: &str| IntCounter::new(name, help).expect("valid wsrep metric");
+
+        let flow_control_paused_seconds = Counter::new(
+            metric_name("wsrep_flow_control_paused_seconds_total"),
+            "Total time replication was paused by flow control, from wsrep_flow_control_paused_ns",
+        )
+        .expect("valid mariadb_wsrep_flow_control_paused_seconds_total metric");
+
+        let evs_repl_latency_seconds = GaugeVec::new(
+            Opts::new(
+                metric_name("wsrep_evs_repl_latency_seconds"),
+                "Group communication replication latency over the last sample window",
+            ),
+            &["stat"],
+        )
+        .expect("valid mariadb_wsrep_evs_repl_latency_seconds metric");
+
+        Self {
+            cluster_size: g(
+                &metric_name("wsrep_cluster_size"),
+                "Number of nodes in the Galera cluster",
+            ),
+            ready: g(
+                &metric_name("wsrep_ready"),
+                "Whether the node accepts queries (1) or not (0)",
+            ),
+            local_state: g(
+                &metric_name("wsrep_local_state"),
+                "Node state (1=joining, 2=donor, 3=joined, 4=synced)",
+            ),
+            flow_control_paused_seconds,
+            evs_delayed_nodes: g(
+                &metric_name("wsrep_evs_delayed_nodes"),
+                "Number of cluster nodes currently reported as delayed (wsrep_evs_delayed)",
+            ),
+            evs_repl_latency_seconds,
+            local_cert_failures: c(
+                &metric_name("wsrep_local_cert_failures_total"),
+                "Local transactions that failed certification",
+            ),
+            local_bf_aborts: c(
+                &metric_name("wsrep_local_bf_aborts_total"),
+                "Local transactions aborted by replicated (brute force) transactions",
+            ),
+            flow_control_paused_ns_last: Arc::new(AtomicI64::new(0)),
+            local_cert_failures_last: Arc::new(AtomicI64::new(0)),
+            local_bf_aborts_last: Arc::new(AtomicI64::new(0)),
+        }
+    }
+
+    /// Get cluster size metric.
+    #[must_use]
+    pub const fn cluster_size(&self) -> &IntGauge {
+        &self.cluster_size
+    }
+
+    /// Get ready metric.
+    #[must_use]
+    pub const fn ready(&self) -> &IntGauge {
+        &self.ready
+    }
+
+    /// Get local state metric.
+    #[must_use]
+    pub const fn local_state(&self) -> &IntGauge {
+        &self.local_state
+    }
+
+    /// Get flow control paused seconds metric.
+    #[must_use]
+    pub const fn flow_control_paused_seconds(&self) -> &Counter {
+        &self.flow_control_paused_seconds
+    }
+
+    /// Get delayed nodes metric.
+    #[must_use]
+    pub const fn evs_delayed_nodes(&self) -> &IntGauge {
+        &self.evs_delayed_nodes
+    }
+
+    /// Get replication latency metric.
+    #[must_use]
+    pub const fn evs_repl_latency_seconds(&self) -> &GaugeVec {
+        &self.evs_repl_latency_seconds
+    }
+
+    /// Get certification failures metric.
+    #[must_use]
+    pub const fn local_cert_failures(&self) -> &IntCounter {
+        &self.local_cert_failures
+    }
+
+    /// Get brute force aborts metric.
+    #[must_use]
+    pub const fn local_bf_aborts(&self) -> &IntCounter {
+        &self.local_bf_aborts
+    }
+
+    /// Collect wsrep status metrics from `SHOW GLOBAL STATUS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails (though queries are best-effort).
+    #[instrument(
+        skip(self, pool),
+        level = "debug",
+        fields(sub_collector = "wsrep_status")
+    )]
+    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SHOW STATUS",
+            db.statement = "SHOW GLOBAL STATUS LIKE 'wsrep_%'",
+            otel.kind = "client"
+        );
+
+        let rows = match sqlx::query_as::<_, (String, String)>("SHOW GLOBAL STATUS LIKE 'wsrep_%'")
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(rows) => rows,
+            Err(e) => {
+                debug!(error = %e, "Failed to query wsrep status");
+                return Ok(());
+            }
+        };
+
+        if rows.is_empty() {
+            debug!("no wsrep status variables; not a Galera node");
+            return Ok(());
+        }
+
+        let status: HashMap<String, String> = rows
+            .into_iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), value))
+            .collect();
+
+        self.apply(&status);
+
+        Ok(())
+    }
+
+    fn apply(&self, status: &HashMap<String, String>) {
+        let int = |key: &str| status.get(key).and_then(|v| v.trim().parse::<i64>().ok());
+
+        if let Some(v) = int("wsrep_cluster_size") {
+            self.cluster_size.set(v);
+        }
+        if let Some(v) = status.get("wsrep_ready") {
+            self.ready.set(i64::from(v.eq_ignore_ascii_case("ON")));
+        }
+        if let Some(v) = int("wsrep_local_state") {
+            self.local_state.set(v);
+        }
+
+        if let Some(ns) = int("wsrep_flow_control_paused_ns") {
+            let previous = self.flow_control_paused_ns_last.swap(ns, Ordering::Relaxed);
+            let delta = i64::try_from(counter_delta(previous, ns)).unwrap_or(0);
+            self.flow_control_paused_seconds
+                .inc_by(i64_to_f64(delta) / NANOS_PER_SECOND);
+        }
+
+        if let Some(delayed) = status.get("wsrep_evs_delayed") {
+            self.evs_delayed_nodes.set(parse_evs_delayed(delayed));
+        }
+
+        self.evs_repl_latency_seconds.reset();
+        if let Some(latency) = status
+            .get("wsrep_evs_repl_latency")
+            .and_then(|v| parse_repl_latency(v))
+        {
+            for (stat, value) in REPL_LATENCY_STATS.iter().zip(latency) {
+                self.evs_repl_latency_seconds
+                    .with_label_values(&[stat])
+                    .set(value);
+            }
+        }
+
+        if let Some(v) = int("wsrep_local_cert_failures") {
+            let previous = self.local_cert_failures_last.swap(v, Ordering::Relaxed);
+            self.local_cert_failures.inc_by(counter_delta(previous, v));
+        }
+        if let Some(v) = int("wsrep_local_bf_aborts") {
+            let previous = self.local_bf_aborts_last.swap(v, Ordering::Relaxed);
+            self.local_bf_aborts.inc_by(counter_delta(previous, v));
+        }
+    }
+}
+
+impl Default for WsrepStatusCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Count entries in `wsrep_evs_delayed`, a comma-separated list of `uuid:address:count`.
+fn parse_evs_delayed(value: &str) -> i64 {
+    let count = value.split(',').filter(|e| !e.trim().is_empty()).count();
+    i64::try_from(count).unwrap_or(i64::MAX)
+}
+
+/// Parse `wsrep_evs_repl_latency` (`min/avg/max/stddev/sample_size`, in seconds).
+/// Returns `None` when there are no samples in the window.
+fn parse_repl_latency(value: &str) -> Option<[f64; 4]> {
+    let parts: Vec<f64> = value
+        .split('/')
+        .map(|p| p.trim().parse::<f64>())
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    match parts.as_slice() {
+        [min, avg, max, stddev, samples] if *samples > 0.0 => Some([*min, *avg, *max, *stddev]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_evs_delayed() {
+        assert_eq!(parse_evs_delayed(""), 0);
+        assert_eq!(
+            parse_evs_delayed("6c1a9a5e-9f1b-11ee-8c2f-8b0f7c5c0a01:tcp://10.0.0.2:4567:3"),
+            1
+        );
+        assert_eq!(
+            parse_evs_delayed("a:tcp://10.0.0.2:4567:1,b:tcp://10.0.0.3:4567:2"),
+            2
+        );
+    }
+
+    #[test]
+    fn test_parse_repl_latency() {
+        assert_eq!(
+            parse_repl_latency("0.000265/0.000491/0.00106/0.000179/11"),
+            Some([0.000_265, 0.000_491, 0.001_06, 0.000_179])
+        );
+        // No samples in the window
+        assert_eq!(parse_repl_latency("0/0/0/0/0"), None);
+        assert_eq!(parse_repl_latency(""), None);
+    }
+
+    #[test]
+    fn test_flow_control_paused_counter_is_monotonic() {
+        let collector = WsrepStatusCollector::new();
+
+        collector.apply(&status(&[("wsrep_flow_control_paused_ns", "1500000000")]));
+        assert!((collector.flow_control_paused_seconds.get() - 1.5).abs() < 1e-9);
+
+        collector.apply(&status(&[("wsrep_flow_control_paused_ns", "2000000000")]));
+        assert!((collector.flow_control_paused_seconds.get() - 2.0).abs() < 1e-9);
+
+        // Node restarted: the counter keeps growing
+        collector.apply(&status(&[("wsrep_flow_control_paused_ns", "250000000")]));
+        assert!((collector.flow_control_paused_seconds.get() - 2.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apply_sets_cluster_metrics() {
+        let collector = WsrepStatusCollector::new();
+
+        collector.apply(&status(&[
+            ("wsrep_cluster_size", "3"),
+            ("wsrep_ready", "ON"),
+            ("wsrep_local_state", "4"),
+            ("wsrep_evs_delayed", "a:tcp://10.0.0.2:4567:1"),
+            ("wsrep_local_cert_failures", "7"),
+            ("wsrep_local_bf_aborts", "2"),
+        ]));
+
+        assert_eq!(collector.cluster_size.get(), 3);
+        assert_eq!(collector.ready.get(), 1);
+        assert_eq!(collector.local_state.get(), 4);
+        assert_eq!(collector.evs_delayed_nodes.get(), 1);
+        assert_eq!(collector.local_cert_failures.get(), 7);
+        assert_eq!(collector.local_bf_aborts.get(), 2);
+
+        collector.apply(&status(&[
+            ("wsrep_local_cert_failures", "9"),
+            ("wsrep_local_bf_aborts", "2"),
+        ]));
+        assert_eq!(collector.local_cert_failures.get(), 9);
+        assert_eq!(collector.local_bf_aborts.get(), 2);
+    }
+}
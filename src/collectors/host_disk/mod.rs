@@ -0,0 +1,219 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{get_datadir, is_access_denied, metric_name},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use std::path::Path;
+use sysinfo::{Disk, Disks};
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Disk usage of the filesystems holding the `MariaDB` data directory and `tmpdir` (opt-in).
+///
+/// Reads the host's mounts with `sysinfo`, so it only makes sense when the exporter runs on
+/// the database host (or sees its directories). The datadir is only reported when `--datadir`
+/// is set; `tmpdir` paths come from the server and are skipped when they don't exist locally.
+#[derive(Clone)]
+pub struct HostDiskCollector {
+    datadir_free: IntGaugeVec,
+    datadir_total: IntGaugeVec,
+    tmpdir_free: IntGaugeVec,
+    tmpdir_total: IntGaugeVec,
+}
+
+impl HostDiskCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new host disk collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            datadir_free: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("datadir_free_bytes"),
+                    "Bytes available to the server on the filesystem holding the data directory",
+                ),
+                &["path"],
+            )
+            .expect("valid mariadb_datadir_free_bytes metric"),
+            datadir_total: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("datadir_total_bytes"),
+                    "Size in bytes of the filesystem holding the data directory",
+                ),
+                &["path"],
+            )
+            .expect("valid mariadb_datadir_total_bytes metric"),
+            tmpdir_free: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("tmpdir_free_bytes"),
+                    "Bytes available to the server on the filesystem holding a tmpdir path",
+                ),
+                &["path"],
+            )
+            .expect("valid mariadb_tmpdir_free_bytes metric"),
+            tmpdir_total: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("tmpdir_total_bytes"),
+                    "Size in bytes of the filesystem holding a tmpdir path",
+                ),
+                &["path"],
+            )
+            .expect("valid mariadb_tmpdir_total_bytes metric"),
+        }
+    }
+
+    /// Set `free`/`total` for the filesystem `dir` lives on, labelled with `dir` as given.
+    fn collect_dir(disks: &Disks, dir: &Path, free: &IntGaugeVec, total: &IntGaugeVec) {
+        // Resolve symlinks so the path matches a mount point
+        let resolved = match dir.canonicalize() {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                debug!(error = %e, path = %dir.display(), "directory not accessible; skipping");
+                return;
+            }
+        };
+
+        let Some(disk) = mount_index(&resolved, disks.list().iter().map(Disk::mount_point))
+            .and_then(|index| disks.list().get(index))
+        else {
+            debug!(path = %resolved.display(), "no mount found for directory; skipping");
+            return;
+        };
+
+        let path = dir.to_string_lossy();
+        free.with_label_values(&[path.as_ref()])
+            .set(i64::try_from(disk.available_space()).unwrap_or(i64::MAX));
+        total
+            .with_label_values(&[path.as_ref()])
+            .set(i64::try_from(disk.total_space()).unwrap_or(i64::MAX));
+    }
+}
+
+/// Index of the mount `path` lives on: the longest mount point it starts with.
+fn mount_index<'a>(path: &Path, mount_points: impl Iterator<Item = &'a Path>) -> Option<usize> {
+    mount_points
+        .enumerate()
+        .filter(|(_, mount)| path.starts_with(mount))
+        .max_by_key(|(_, mount)| mount.components().count())
+        .map(|(index, _)| index)
+}
+
+impl Default for HostDiskCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for HostDiskCollector {
+    fn name(&self) -> &'static str {
+        "host_disk"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "host_disk")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.datadir_free.clone()))?;
+        registry.register(Box::new(self.datadir_total.clone()))?;
+        registry.register(Box::new(self.tmpdir_free.clone()))?;
+        registry.register(Box::new(self.tmpdir_total.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "host_disk", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // Reset so a directory that went missing stops being reported
+            self.datadir_free.reset();
+            self.datadir_total.reset();
+            self.tmpdir_free.reset();
+            self.tmpdir_total.reset();
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "SELECT @@tmpdir",
+                otel.kind = "client"
+            );
+
+            let tmpdir = match sqlx::query_scalar::<_, String>("SELECT @@tmpdir")
+                .fetch_one(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(tmpdir) => Some(tmpdir),
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => {
+                    debug!(error = %e, "failed to read @@tmpdir; skipping tmpdir");
+                    None
+                }
+            };
+
+            let disks = Disks::new_with_refreshed_list();
+
+            if let Some(datadir) = get_datadir() {
+                Self::collect_dir(&disks, datadir, &self.datadir_free, &self.datadir_total);
+            } else {
+                debug!("--datadir not set; skipping datadir disk collection");
+            }
+
+            // The server round-robins over a `:`-separated list
+            for dir in tmpdir.iter().flat_map(|tmpdir| tmpdir.split(':')) {
+                if !dir.trim().is_empty() {
+                    Self::collect_dir(
+                        &disks,
+                        Path::new(dir.trim()),
+                        &self.tmpdir_free,
+                        &self.tmpdir_total,
+                    );
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mount_index_picks_longest_mount() {
+        let mounts = [
+            Path::new("/"),
+            Path::new("/var"),
+            Path::new("/var/lib/mysql"),
+            Path::new("/var/lib/mysql-binlog"),
+        ];
+
+        assert_eq!(
+            mount_index(Path::new("/var/lib/mysql/db1"), mounts.into_iter()),
+            Some(2)
+        );
+        assert_eq!(
+            mount_index(Path::new("/var/lib/mysqld"), mounts.into_iter()),
+            Some(1)
+        );
+        assert_eq!(mount_index(Path::new("/srv"), mounts.into_iter()), Some(0));
+        assert_eq!(
+            mount_index(Path::new("/srv"), [Path::new("/var")].into_iter()),
+            None
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::collectors::util::metric_name;
+use anyhow::Result;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Per-instance buffer pool stats from `information_schema.INNODB_BUFFER_POOL_STATS`.
+///
+/// The aggregate `mariadb_innodb_buffer_pool_pages_*` gauges from the default collector hide
+/// imbalance between instances, so these use a distinct `buffer_pool_instance` prefix and a
+/// `pool_id` label. Requires the `PROCESS` privilege.
+#[derive(Clone)]
+pub struct BufferPoolStatsCollector {
+    pages_total: IntGaugeVec,
+    pages_free: IntGaugeVec,
+    pages_data: IntGaugeVec,
+    pages_dirty: IntGaugeVec,
+    pending_reads: IntGaugeVec,
+}
+
+impl BufferPoolStatsCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new buffer pool stats collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        let vec = |name: &str, help: &str| {
+            IntGaugeVec::new(Opts::new(name, help), &["pool_id"]).expect("valid buffer pool metric")
+        };
+
+        Self {
+            pages_total: vec(
+                &metric_name("innodb_buffer_pool_instance_pages_total"),
+                "Total pages in the buffer pool instance",
+            ),
+            pages_free: vec(
+                &metric_name("innodb_buffer_pool_instance_pages_free"),
+                "Free pages in the buffer pool instance",
+            ),
+            pages_data: vec(
+                &metric_name("innodb_buffer_pool_instance_pages_data"),
+                "Pages containing data in the buffer pool instance",
+            ),
+            pages_dirty: vec(
+                &metric_name("innodb_buffer_pool_instance_pages_dirty"),
+                "Modified (dirty) pages in the buffer pool instance",
+            ),
+            pending_reads: vec(
+                &metric_name("innodb_buffer_pool_instance_pending_reads"),
+                "Pages waiting to be read into the buffer pool instance",
+            ),
+        }
+    }
+
+    /// Get total pages metric.
+    #[must_use]
+    pub const fn pages_total(&self) -> &IntGaugeVec {
+        &self.pages_total
+    }
+
+    /// Get free pages metric.
+    #[must_use]
+    pub const fn pages_free(&self) -> &IntGaugeVec {
+        &self.pages_free
+    }
+
+    /// Get data pages metric.
+    #[must_use]
+    pub const fn pages_data(&self) -> &IntGaugeVec {
+        &self.pages_data
+    }
+
+    /// Get dirty pages metric.
+    #[must_use]
+    pub const fn pages_dirty(&self) -> &IntGaugeVec {
+        &self.pages_dirty
+    }
+
+    /// Get pending reads metric.
+    #[must_use]
+    pub const fn pending_reads(&self) -> &IntGaugeVec {
+        &self.pending_reads
+    }
+
+    fn reset(&self) {
+        self.pages_total.reset();
+        self.pages_free.reset();
+        self.pages_data.reset();
+        self.pages_dirty.reset();
+        self.pending_reads.reset();
+    }
+
+    /// Collect per-instance buffer pool metrics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails (though queries are best-effort).
+    #[instrument(skip(self, pool), level = "debug", fields(sub_collector = "buffer_pool_stats"))]
+    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        self.reset();
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT POOL_ID, POOL_SIZE, FREE_BUFFERS, DATABASE_PAGES, MODIFIED_DATABASE_PAGES, PENDING_READS FROM information_schema.INNODB_BUFFER_POOL_STATS",
+            otel.kind = "client"
+        );
+
+        let rows = match sqlx::query_as::<_, (i64, i64, i64, i64, i64, i64)>(
+            "SELECT CAST(POOL_ID AS SIGNED),
+                    CAST(POOL_SIZE AS SIGNED),
+                    CAST(FREE_BUFFERS AS SIGNED),
+                    CAST(DATABASE_PAGES AS SIGNED),
+                    CAST(MODIFIED_DATABASE_PAGES AS SIGNED),
+                    CAST(PENDING_READS AS SIGNED)
+             FROM information_schema.INNODB_BUFFER_POOL_STATS",
+        )
+        .fetch_all(pool)
+        .instrument(span)
+        .await
+        {
+            Ok(rows) => rows,
+            Err(e) => {
+                debug!("INNODB_BUFFER_POOL_STATS not readable (requires PROCESS): {}", e);
+                return Ok(());
+            }
+        };
+
+        for (pool_id, size, free, data, dirty, pending) in rows {
+            let id = pool_id.to_string();
+            let labels = [id.as_str()];
+            self.pages_total.with_label_values(&labels).set(size);
+            self.pages_free.with_label_values(&labels).set(free);
+            self.pages_data.with_label_values(&labels).set(data);
+            self.pages_dirty.with_label_values(&labels).set(dirty);
+            self.pending_reads.with_label_values(&labels).set(pending);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for BufferPoolStatsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,12 +1,15 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar, fixture::StatusSnapshot};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use tracing::instrument;
 
+pub mod buffer_pool;
 pub mod status;
+pub mod tablespaces;
+use buffer_pool::BufferPoolStatsCollector;
 use status::StatusParser;
+use tablespaces::TablespacesCollector;
 
 /// `InnoDB` engine status collector (requires `SHOW ENGINE INNODB STATUS` privilege).
 ///
@@ -15,9 +18,14 @@ use status::StatusParser;
 /// - Transaction states and history
 /// - Semaphore information
 /// - Adaptive hash index stats
+///
+/// Also breaks down buffer pool usage per instance from `INNODB_BUFFER_POOL_STATS` and
+/// reports the on-disk size of the largest tablespace files.
 #[derive(Clone)]
 pub struct InnodbCollector {
     status: StatusParser,
+    buffer_pool: BufferPoolStatsCollector,
+    tablespaces: TablespacesCollector,
 }
 
 impl InnodbCollector {
@@ -26,6 +34,8 @@ impl InnodbCollector {
     pub fn new() -> Self {
         Self {
             status: StatusParser::new(),
+            buffer_pool: BufferPoolStatsCollector::new(),
+            tablespaces: TablespacesCollector::new(),
         }
     }
 }
@@ -47,16 +57,43 @@ impl Collector for InnodbCollector {
         err,
         fields(collector = "innodb")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.status.sample_interval_seconds().clone()))?;
         registry.register(Box::new(self.status.lsn_current().clone()))?;
         registry.register(Box::new(self.status.lsn_flushed().clone()))?;
         registry.register(Box::new(self.status.lsn_checkpoint().clone()))?;
         registry.register(Box::new(self.status.checkpoint_age().clone()))?;
+        registry.register(Box::new(self.status.checkpoint_age_ratio().clone()))?;
+        registry.register(Box::new(self.status.seconds_since_checkpoint().clone()))?;
+        registry.register(Box::new(self.status.redo_write_rate().clone()))?;
+        registry.register(Box::new(self.status.redo_log_capacity().clone()))?;
+        registry.register(Box::new(self.status.redo_log_write_rate_ratio().clone()))?;
         registry.register(Box::new(self.status.active_transactions().clone()))?;
+        registry.register(Box::new(self.status.oldest_transaction_seconds().clone()))?;
+        registry.register(Box::new(self.status.purge_trx_lag().clone()))?;
         registry.register(Box::new(self.status.semaphore_waits().clone()))?;
         registry.register(Box::new(self.status.semaphore_wait_time_ms().clone()))?;
+        registry.register(Box::new(self.status.semaphore_wait_time_seconds().clone()))?;
+        registry.register(Box::new(self.status.mutex_os_waits().clone()))?;
+        registry.register(Box::new(self.status.rw_shared_os_waits().clone()))?;
+        registry.register(Box::new(self.status.rw_excl_os_waits().clone()))?;
+        registry.register(Box::new(self.status.adaptive_hash_index_enabled().clone()))?;
         registry.register(Box::new(self.status.adaptive_hash_searches().clone()))?;
         registry.register(Box::new(self.status.adaptive_hash_searches_btree().clone()))?;
+        registry.register(Box::new(self.status.adaptive_hash_table_size().clone()))?;
+        registry.register(Box::new(self.status.adaptive_hash_node_heap_buffers().clone()))?;
+        registry.register(Box::new(self.status.change_buffer_size().clone()))?;
+        registry.register(Box::new(self.status.change_buffer_free_list_len().clone()))?;
+        registry.register(Box::new(self.status.change_buffer_merges().clone()))?;
+        registry.register(Box::new(self.status.pages_made_young().clone()))?;
+        registry.register(Box::new(self.status.pages_made_not_young().clone()))?;
+        registry.register(Box::new(self.buffer_pool.pages_total().clone()))?;
+        registry.register(Box::new(self.buffer_pool.pages_free().clone()))?;
+        registry.register(Box::new(self.buffer_pool.pages_data().clone()))?;
+        registry.register(Box::new(self.buffer_pool.pages_dirty().clone()))?;
+        registry.register(Box::new(self.buffer_pool.pending_reads().clone()))?;
+        registry.register(Box::new(self.tablespaces.file_size_bytes().clone()))?;
+        registry.register(Box::new(self.tablespaces.allocated_size_bytes().clone()))?;
         Ok(())
     }
 
@@ -64,11 +101,22 @@ impl Collector for InnodbCollector {
     fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             self.status.collect(pool).await?;
+            self.buffer_pool.collect(pool).await?;
+            self.tablespaces.collect(pool).await?;
             Ok(())
         })
     }
 
+    fn collect_from_fixture(&self, snapshot: &StatusSnapshot) -> Result<()> {
+        self.status.collect_from_fixture(snapshot)
+    }
+
     fn enabled_by_default(&self) -> bool {
         false
     }
+
+    // SHOW ENGINE INNODB STATUS and the INNODB_* information_schema tables
+    fn required_privileges(&self) -> &[&str] {
+        &["PROCESS"]
+    }
 }
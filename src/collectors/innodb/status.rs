@@ -1,33 +1,74 @@
+use crate::collectors::{fixture::StatusSnapshot, i64_to_f64, util::metric_name};
 use anyhow::{Context, Result};
-use prometheus::IntGauge;
+use prometheus::{Gauge, IntGauge, IntGaugeVec, Opts};
 use sqlx::{MySqlPool, Row};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// Redo log size and adaptive hash index variables. `MySQL` 8.0.30+ sizes the redo log with
+/// `innodb_redo_log_capacity`; `MariaDB` 10.5+ always uses a single log file and dropped
+/// `innodb_log_files_in_group` in 10.6.
+const VARIABLES_QUERY: &str = "SHOW GLOBAL VARIABLES WHERE Variable_name IN \
+     ('innodb_log_file_size', 'innodb_log_files_in_group', 'innodb_redo_log_capacity', \
+     'innodb_adaptive_hash_index')";
+
 /// Parser for SHOW ENGINE INNODB STATUS output.
 #[derive(Clone)]
 pub struct StatusParser {
+    // Header: window the per-second averages were computed over
+    sample_interval_seconds: IntGauge,
+
     // LSN and checkpoint metrics
     lsn_current: IntGauge,
     lsn_flushed: IntGauge,
     lsn_checkpoint: IntGauge,
     checkpoint_age: IntGauge,
+    checkpoint_age_ratio: Gauge,
+    seconds_since_checkpoint: Gauge,
+    // LSN and time of the previous scrape, for the redo write rate
+    last_lsn: Arc<Mutex<Option<(Instant, i64)>>>,
+    redo_write_rate: Gauge,
+    redo_log_capacity: IntGauge,
+    redo_log_write_rate_ratio: Gauge,
 
     // Transaction metrics
     trx_active_transactions: IntGauge,
+    trx_oldest_seconds: IntGauge,
+    purge_trx_lag: IntGauge,
 
     // Semaphore metrics
     semaphore_waits: IntGauge,
     semaphore_wait_time_ms: IntGauge,
+    semaphore_wait_time_seconds: Gauge,
+    mutex_os_waits: IntGauge,
+    rw_shared_os_waits: IntGauge,
+    rw_excl_os_waits: IntGauge,
 
     // Adaptive hash index
+    adaptive_hash_index_enabled: IntGauge,
     adaptive_hash_searches: IntGauge,
     adaptive_hash_searches_btree: IntGauge,
+    adaptive_hash_table_size: IntGaugeVec,
+    adaptive_hash_node_heap_buffers: IntGaugeVec,
+
+    // Change buffer (insert buffer)
+    change_buffer_size: IntGauge,
+    change_buffer_free_list_len: IntGauge,
+    change_buffer_merges: IntGauge,
+
+    // Buffer pool LRU
+    pages_made_young: IntGauge,
+    pages_made_not_young: IntGauge,
 }
 
 impl StatusParser {
     #[must_use]
-    #[allow(clippy::expect_used)]
+    #[allow(clippy::expect_used, clippy::too_many_lines)]
     /// Create a new status parser.
     ///
     /// # Panics
@@ -35,56 +76,169 @@ impl StatusParser {
     /// Panics if metric names are invalid (should not occur with static names).
     pub fn new() -> Self {
         Self {
+            sample_interval_seconds: IntGauge::new(
+                metric_name("innodb_status_sample_interval_seconds"),
+                "Seconds covered by the per-second averages in SHOW ENGINE INNODB STATUS",
+            )
+            .expect("valid mariadb_innodb_status_sample_interval_seconds metric"),
             lsn_current: IntGauge::new(
-                "mariadb_innodb_lsn_current",
+                metric_name("innodb_lsn_current"),
                 "Current InnoDB log sequence number (LSN)",
             )
             .expect("valid mariadb_innodb_lsn_current metric"),
             lsn_flushed: IntGauge::new(
-                "mariadb_innodb_lsn_flushed",
+                metric_name("innodb_lsn_flushed"),
                 "LSN flushed to disk",
             )
             .expect("valid mariadb_innodb_lsn_flushed metric"),
             lsn_checkpoint: IntGauge::new(
-                "mariadb_innodb_lsn_checkpoint",
+                metric_name("innodb_lsn_checkpoint"),
                 "Last checkpoint LSN",
             )
             .expect("valid mariadb_innodb_lsn_checkpoint metric"),
             checkpoint_age: IntGauge::new(
-                "mariadb_innodb_checkpoint_age_bytes",
+                metric_name("innodb_checkpoint_age_bytes"),
                 "InnoDB checkpoint age in bytes (LSN current - LSN checkpoint)",
             )
             .expect("valid mariadb_innodb_checkpoint_age_bytes metric"),
+            checkpoint_age_ratio: Gauge::new(
+                metric_name("innodb_checkpoint_age_ratio"),
+                "InnoDB checkpoint age as a fraction of the total redo log size",
+            )
+            .expect("valid mariadb_innodb_checkpoint_age_ratio metric"),
+            seconds_since_checkpoint: Gauge::new(
+                metric_name("innodb_seconds_since_checkpoint"),
+                "Estimated seconds since the last checkpoint: checkpoint age divided by the redo write rate since the previous scrape",
+            )
+            .expect("valid mariadb_innodb_seconds_since_checkpoint metric"),
+            last_lsn: Arc::new(Mutex::new(None)),
+            redo_write_rate: Gauge::new(
+                metric_name("innodb_redo_write_bytes_per_second"),
+                "Redo log bytes written per second since the previous scrape (LSN delta)",
+            )
+            .expect("valid mariadb_innodb_redo_write_bytes_per_second metric"),
+            redo_log_capacity: IntGauge::new(
+                metric_name("innodb_redo_log_capacity_bytes"),
+                "Total redo log size in bytes (innodb_redo_log_capacity, or innodb_log_file_size * innodb_log_files_in_group)",
+            )
+            .expect("valid mariadb_innodb_redo_log_capacity_bytes metric"),
+            redo_log_write_rate_ratio: Gauge::new(
+                metric_name("innodb_redo_log_write_rate_ratio"),
+                "Redo log capacity written per hour at the current write rate; above 1 the redo log wraps more than once an hour",
+            )
+            .expect("valid mariadb_innodb_redo_log_write_rate_ratio metric"),
             trx_active_transactions: IntGauge::new(
-                "mariadb_innodb_active_transactions",
+                metric_name("innodb_active_transactions"),
                 "Number of active InnoDB transactions",
             )
             .expect("valid mariadb_innodb_active_transactions metric"),
+            trx_oldest_seconds: IntGauge::new(
+                metric_name("innodb_oldest_transaction_seconds"),
+                "Age in seconds of the oldest active InnoDB transaction",
+            )
+            .expect("valid mariadb_innodb_oldest_transaction_seconds metric"),
+            purge_trx_lag: IntGauge::new(
+                metric_name("innodb_purge_trx_lag"),
+                "Transaction ids not yet purged (Trx id counter minus Purge done for trx's n:o)",
+            )
+            .expect("valid mariadb_innodb_purge_trx_lag metric"),
             semaphore_waits: IntGauge::new(
-                "mariadb_innodb_semaphore_waits_total",
+                metric_name("innodb_semaphore_waits_total"),
                 "Total number of semaphore waits",
             )
             .expect("valid mariadb_innodb_semaphore_waits_total metric"),
             semaphore_wait_time_ms: IntGauge::new(
-                "mariadb_innodb_semaphore_wait_time_ms_total",
-                "Total semaphore wait time in milliseconds",
+                metric_name("innodb_semaphore_wait_time_ms_total"),
+                "Total semaphore wait time in milliseconds (deprecated, use the _seconds_total metric)",
             )
             .expect("valid mariadb_innodb_semaphore_wait_time_ms_total metric"),
+            semaphore_wait_time_seconds: Gauge::new(
+                metric_name("innodb_semaphore_wait_time_seconds_total"),
+                "Total semaphore wait time in seconds",
+            )
+            .expect("valid mariadb_innodb_semaphore_wait_time_seconds_total metric"),
+            mutex_os_waits: IntGauge::new(
+                metric_name("innodb_mutex_os_waits"),
+                "Mutex waits that fell back to an OS wait (Mutex spin waits line)",
+            )
+            .expect("valid mariadb_innodb_mutex_os_waits metric"),
+            rw_shared_os_waits: IntGauge::new(
+                metric_name("innodb_rw_shared_os_waits"),
+                "Shared rw-lock waits that fell back to an OS wait (RW-shared spins line)",
+            )
+            .expect("valid mariadb_innodb_rw_shared_os_waits metric"),
+            rw_excl_os_waits: IntGauge::new(
+                metric_name("innodb_rw_excl_os_waits"),
+                "Exclusive rw-lock waits that fell back to an OS wait (RW-excl spins line)",
+            )
+            .expect("valid mariadb_innodb_rw_excl_os_waits metric"),
+            adaptive_hash_index_enabled: IntGauge::new(
+                metric_name("innodb_adaptive_hash_index_enabled"),
+                "Whether the adaptive hash index is enabled (@@innodb_adaptive_hash_index)",
+            )
+            .expect("valid mariadb_innodb_adaptive_hash_index_enabled metric"),
             adaptive_hash_searches: IntGauge::new(
-                "mariadb_innodb_adaptive_hash_searches_total",
+                metric_name("innodb_adaptive_hash_searches_total"),
                 "Adaptive hash index searches",
             )
             .expect("valid mariadb_innodb_adaptive_hash_searches_total metric"),
             adaptive_hash_searches_btree: IntGauge::new(
-                "mariadb_innodb_adaptive_hash_searches_btree_total",
+                metric_name("innodb_adaptive_hash_searches_btree_total"),
                 "Adaptive hash index searches requiring B-tree lookup",
             )
             .expect("valid mariadb_innodb_adaptive_hash_searches_btree_total metric"),
+            adaptive_hash_table_size: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("innodb_adaptive_hash_table_size"),
+                    "Cells in the adaptive hash index table, per partition",
+                ),
+                &["partition"],
+            )
+            .expect("valid mariadb_innodb_adaptive_hash_table_size metric"),
+            adaptive_hash_node_heap_buffers: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("innodb_adaptive_hash_node_heap_buffers"),
+                    "Buffer pool pages used by the adaptive hash index node heap, per partition",
+                ),
+                &["partition"],
+            )
+            .expect("valid mariadb_innodb_adaptive_hash_node_heap_buffers metric"),
+            change_buffer_size: IntGauge::new(
+                metric_name("innodb_change_buffer_size"),
+                "Change buffer size in pages (Ibuf: size)",
+            )
+            .expect("valid mariadb_innodb_change_buffer_size metric"),
+            change_buffer_free_list_len: IntGauge::new(
+                metric_name("innodb_change_buffer_free_list_len"),
+                "Free pages in the change buffer (Ibuf: free list len)",
+            )
+            .expect("valid mariadb_innodb_change_buffer_free_list_len metric"),
+            change_buffer_merges: IntGauge::new(
+                metric_name("innodb_change_buffer_merges"),
+                "Change buffer merges since startup (Ibuf: merges)",
+            )
+            .expect("valid mariadb_innodb_change_buffer_merges metric"),
+            pages_made_young: IntGauge::new(
+                metric_name("innodb_buffer_pool_pages_made_young_total"),
+                "Pages moved to the young end of the buffer pool LRU list (Pages made young)",
+            )
+            .expect("valid mariadb_innodb_buffer_pool_pages_made_young_total metric"),
+            pages_made_not_young: IntGauge::new(
+                metric_name("innodb_buffer_pool_pages_made_not_young_total"),
+                "Pages left in the old sublist because they were accessed within innodb_old_blocks_time (not young)",
+            )
+            .expect("valid mariadb_innodb_buffer_pool_pages_made_not_young_total metric"),
         }
     }
 
     // Getter methods for metrics (used in mod.rs for registration)
     
+    /// Get sample interval metric.
+    #[must_use]
+    pub fn sample_interval_seconds(&self) -> &IntGauge {
+        &self.sample_interval_seconds
+    }
+
     /// Get LSN current metric.
     #[must_use]
     pub fn lsn_current(&self) -> &IntGauge {
@@ -109,24 +263,96 @@ impl StatusParser {
         &self.checkpoint_age
     }
 
+    /// Get checkpoint age ratio metric.
+    #[must_use]
+    pub fn checkpoint_age_ratio(&self) -> &Gauge {
+        &self.checkpoint_age_ratio
+    }
+
+    /// Get seconds since checkpoint metric.
+    #[must_use]
+    pub fn seconds_since_checkpoint(&self) -> &Gauge {
+        &self.seconds_since_checkpoint
+    }
+
+    /// Get redo write rate metric.
+    #[must_use]
+    pub fn redo_write_rate(&self) -> &Gauge {
+        &self.redo_write_rate
+    }
+
+    /// Get redo log capacity metric.
+    #[must_use]
+    pub fn redo_log_capacity(&self) -> &IntGauge {
+        &self.redo_log_capacity
+    }
+
+    /// Get redo log write rate ratio metric.
+    #[must_use]
+    pub fn redo_log_write_rate_ratio(&self) -> &Gauge {
+        &self.redo_log_write_rate_ratio
+    }
+
     /// Get active transactions metric.
     #[must_use]
     pub fn active_transactions(&self) -> &IntGauge {
         &self.trx_active_transactions
     }
 
+    /// Get oldest transaction age metric.
+    #[must_use]
+    pub fn oldest_transaction_seconds(&self) -> &IntGauge {
+        &self.trx_oldest_seconds
+    }
+
+    /// Get purge lag metric.
+    #[must_use]
+    pub fn purge_trx_lag(&self) -> &IntGauge {
+        &self.purge_trx_lag
+    }
+
     /// Get semaphore waits metric.
     #[must_use]
     pub fn semaphore_waits(&self) -> &IntGauge {
         &self.semaphore_waits
     }
 
-    /// Get semaphore wait time metric.
+    /// Get semaphore wait time metric (milliseconds, deprecated).
     #[must_use]
     pub fn semaphore_wait_time_ms(&self) -> &IntGauge {
         &self.semaphore_wait_time_ms
     }
 
+    /// Get semaphore wait time metric in seconds.
+    #[must_use]
+    pub fn semaphore_wait_time_seconds(&self) -> &Gauge {
+        &self.semaphore_wait_time_seconds
+    }
+
+    /// Get mutex OS waits metric.
+    #[must_use]
+    pub fn mutex_os_waits(&self) -> &IntGauge {
+        &self.mutex_os_waits
+    }
+
+    /// Get shared rw-lock OS waits metric.
+    #[must_use]
+    pub fn rw_shared_os_waits(&self) -> &IntGauge {
+        &self.rw_shared_os_waits
+    }
+
+    /// Get exclusive rw-lock OS waits metric.
+    #[must_use]
+    pub fn rw_excl_os_waits(&self) -> &IntGauge {
+        &self.rw_excl_os_waits
+    }
+
+    /// Get adaptive hash index enabled metric.
+    #[must_use]
+    pub fn adaptive_hash_index_enabled(&self) -> &IntGauge {
+        &self.adaptive_hash_index_enabled
+    }
+
     /// Get adaptive hash searches metric.
     #[must_use]
     pub fn adaptive_hash_searches(&self) -> &IntGauge {
@@ -139,6 +365,48 @@ impl StatusParser {
         &self.adaptive_hash_searches_btree
     }
 
+    /// Get adaptive hash table size metric.
+    #[must_use]
+    pub fn adaptive_hash_table_size(&self) -> &IntGaugeVec {
+        &self.adaptive_hash_table_size
+    }
+
+    /// Get adaptive hash node heap metric.
+    #[must_use]
+    pub fn adaptive_hash_node_heap_buffers(&self) -> &IntGaugeVec {
+        &self.adaptive_hash_node_heap_buffers
+    }
+
+    /// Get change buffer size metric.
+    #[must_use]
+    pub fn change_buffer_size(&self) -> &IntGauge {
+        &self.change_buffer_size
+    }
+
+    /// Get change buffer free list length metric.
+    #[must_use]
+    pub fn change_buffer_free_list_len(&self) -> &IntGauge {
+        &self.change_buffer_free_list_len
+    }
+
+    /// Get change buffer merges metric.
+    #[must_use]
+    pub fn change_buffer_merges(&self) -> &IntGauge {
+        &self.change_buffer_merges
+    }
+
+    /// Get pages made young metric.
+    #[must_use]
+    pub fn pages_made_young(&self) -> &IntGauge {
+        &self.pages_made_young
+    }
+
+    /// Get pages made not young metric.
+    #[must_use]
+    pub fn pages_made_not_young(&self) -> &IntGauge {
+        &self.pages_made_not_young
+    }
+
     /// Collect `InnoDB` status metrics from database.
     ///
     /// # Errors
@@ -170,24 +438,131 @@ impl StatusParser {
         // Parse the status text
         self.parse(&status_text)?;
 
+        self.collect_variables(pool).await;
+
+        Ok(())
+    }
+
+    /// Relate the checkpoint age to the redo log size and report whether the adaptive hash
+    /// index is on. Best-effort: both are left unchanged if the variables can't be read.
+    async fn collect_variables(&self, pool: &MySqlPool) {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SHOW VARIABLES",
+            db.statement = VARIABLES_QUERY,
+            otel.kind = "client"
+        );
+
+        let vars: HashMap<String, String> =
+            match sqlx::query_as::<_, (String, String)>(VARIABLES_QUERY)
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => rows
+                    .into_iter()
+                    .map(|(name, value)| (name.to_ascii_lowercase(), value))
+                    .collect(),
+                Err(e) => {
+                    debug!(error = %e, "failed to query InnoDB variables");
+                    return;
+                }
+            };
+
+        self.apply_variables(&vars);
+    }
+
+    /// Update the metrics from a captured snapshot: its `SHOW ENGINE INNODB STATUS` text, if
+    /// any, then the redo log and adaptive hash index variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing the status text fails critically.
+    pub fn collect_from_fixture(&self, snapshot: &StatusSnapshot) -> Result<()> {
+        if let Some(status) = &snapshot.innodb_status {
+            self.parse(status)?;
+        }
+
+        self.apply_variables(&snapshot.variables_map());
+
         Ok(())
     }
 
+    fn apply_variables(&self, vars: &HashMap<String, String>) {
+        if let Some(capacity) = redo_log_capacity(vars) {
+            self.redo_log_capacity.set(capacity);
+            self.set_checkpoint_age_ratio(capacity);
+            self.set_redo_log_write_rate_ratio(capacity);
+        } else {
+            debug!("redo log size unknown; skipping checkpoint age and write rate ratios");
+        }
+
+        // Without the flag, zero hash searches can't be told apart from a disabled index
+        if let Some(value) = vars.get("innodb_adaptive_hash_index") {
+            let value = value.trim();
+            self.adaptive_hash_index_enabled
+                .set(i64::from(value.eq_ignore_ascii_case("ON") || value == "1"));
+        }
+    }
+
+    fn set_checkpoint_age_ratio(&self, capacity: i64) {
+        let ratio = i64_to_f64(self.checkpoint_age.get()) / i64_to_f64(capacity);
+        self.checkpoint_age_ratio.set(ratio);
+        debug!(
+            checkpoint_age_ratio = ratio,
+            "calculated checkpoint age ratio"
+        );
+    }
+
+    /// How many times the redo log would be filled in an hour at the write rate measured
+    /// between the last two scrapes; the usual sizing advice is a log holding about an hour
+    /// of writes, i.e. a ratio near or below 1. It is 0 until the second scrape.
+    fn set_redo_log_write_rate_ratio(&self, capacity: i64) {
+        let ratio = self.redo_write_rate.get() * 3600.0 / i64_to_f64(capacity);
+        self.redo_log_write_rate_ratio.set(ratio);
+        debug!(
+            redo_log_write_rate_ratio = ratio,
+            "calculated redo log write rate ratio"
+        );
+    }
+
     /// Parse SHOW ENGINE INNODB STATUS output.
     ///
     /// # Errors
     ///
     /// Returns an error if parsing fails critically.
+    #[allow(clippy::too_many_lines)]
     pub fn parse(&self, status: &str) -> Result<()> {
         let mut lsn_current: Option<i64> = None;
         let mut lsn_checkpoint: Option<i64> = None;
         let mut active_trx = 0;
+        let mut oldest_trx_seconds = 0;
+        let mut trx_id_counter: Option<i64> = None;
+        let mut purge_done_trx: Option<i64> = None;
         let mut semaphore_waits = 0;
         let mut semaphore_wait_time_ms = 0.0;
+        let mut hash_partition = 0_usize;
+        let mut lru_parsed = false;
+        // 0 when the header is missing, instead of the previous snapshot's window
+        let mut sample_interval_seconds = 0;
+
+        // Partitions are numbered by position; reset in case innodb_adaptive_hash_index_parts changed
+        self.adaptive_hash_table_size.reset();
+        self.adaptive_hash_node_heap_buffers.reset();
 
         for line in status.lines() {
             let line = line.trim();
 
+            // Parse the averaging window from the header
+            // Example: "Per second averages calculated from the last 18 seconds"
+            if let Some(rest) = line.strip_prefix("Per second averages calculated from the last")
+                && let Some(value) = rest.split_whitespace().next()
+                && let Ok(seconds) = value.parse::<i64>()
+            {
+                sample_interval_seconds = seconds;
+                debug!(sample_interval_seconds = seconds, "parsed status sample interval");
+            }
             // Parse LSN information
             // Example: "Log sequence number          123456789"
             if line.starts_with("Log sequence number")
@@ -215,10 +590,23 @@ impl StatusParser {
                 self.lsn_checkpoint.set(lsn);
                 debug!(lsn_checkpoint = lsn, "parsed LSN checkpoint");
             }
-            // Count active transactions
+            // Count active transactions and track the oldest one
             // Example: "---TRANSACTION 123456, ACTIVE 5 sec"
             else if line.starts_with("---TRANSACTION") && line.contains("ACTIVE") {
                 active_trx += 1;
+                if let Some(seconds) = transaction_active_seconds(line) {
+                    oldest_trx_seconds = oldest_trx_seconds.max(seconds);
+                }
+            }
+            // Example: "Trx id counter 1234567"
+            else if let Some(value) = line.strip_prefix("Trx id counter")
+                && let Ok(id) = value.trim().parse::<i64>()
+            {
+                trx_id_counter = Some(id);
+            }
+            // Example: "Purge done for trx's n:o < 1234500 undo n:o < 0 state: running"
+            else if let Some(id) = purge_done_trx_id(line) {
+                purge_done_trx = Some(id);
             }
             // Parse individual semaphore waits/times
             // Example: "--Thread 123 has waited at btr0cur.cc line 123 for 5.00 seconds the semaphore:"
@@ -239,32 +627,35 @@ impl StatusParser {
                 && let Ok(waits) = num_str.parse::<i64>()
             {
                 semaphore_waits += waits;
+                self.record_os_waits(line, waits);
                 debug!(semaphore_waits = waits, "parsed semaphore waits part");
             }
             // Parse adaptive hash index
             // Example: "123456 hash searches/s, 12345 non-hash searches/s"
             else if line.contains("hash searches/s") {
-                let parts: Vec<&str> = line.split(',').collect();
-                if let Some(hash_part) = parts.first()
-                    && let Some(value) = hash_part.split_whitespace().next()
-                    && let Ok(searches) = value.parse::<i64>()
-                {
-                    self.adaptive_hash_searches.set(searches);
-                    debug!(
-                        adaptive_hash_searches = searches,
-                        "parsed adaptive hash searches"
-                    );
-                }
-                if let Some(btree_part) = parts.get(1)
-                    && let Some(value) = btree_part.split_whitespace().next()
-                    && let Ok(searches) = value.parse::<i64>()
-                {
-                    self.adaptive_hash_searches_btree.set(searches);
-                    debug!(
-                        adaptive_hash_searches_btree = searches,
-                        "parsed adaptive hash B-tree searches"
-                    );
-                }
+                self.parse_adaptive_hash(line);
+            }
+            // One line per adaptive hash index partition
+            // Example: "Hash table size 34679, node heap has 2 buffer(s)"
+            else if let Some((size, buffers)) = hash_table_partition(line) {
+                self.record_hash_partition(hash_partition, size, buffers);
+                hash_partition += 1;
+            }
+            // Example: "Ibuf: size 1, free list len 0, seg size 2, 0 merges"
+            else if let Some((size, free_list_len, merges)) = ibuf_line(line) {
+                self.change_buffer_size.set(size);
+                self.change_buffer_free_list_len.set(free_list_len);
+                self.change_buffer_merges.set(merges);
+                debug!(size, free_list_len, merges, "parsed change buffer");
+            }
+            // The BUFFER POOL AND MEMORY total comes first; with several buffer pool
+            // instances, INDIVIDUAL BUFFER POOL INFO repeats the line per instance
+            // Example: "Pages made young 2213, not young 0"
+            else if !lru_parsed && let Some((young, not_young)) = pages_made_young_line(line) {
+                lru_parsed = true;
+                self.pages_made_young.set(young);
+                self.pages_made_not_young.set(not_young);
+                debug!(young, not_young, "parsed buffer pool LRU movement");
             }
         }
 
@@ -273,12 +664,25 @@ impl StatusParser {
             let age = current - checkpoint;
             self.checkpoint_age.set(age);
             debug!(checkpoint_age = age, "calculated checkpoint age");
+            let rate = self.measure_redo_write_rate(current, Instant::now());
+            self.set_seconds_since_checkpoint(age, rate);
+        }
+
+        // Transactions the purge thread hasn't caught up with yet
+        if let (Some(counter), Some(purged)) = (trx_id_counter, purge_done_trx) {
+            let lag = (counter - purged).max(0);
+            self.purge_trx_lag.set(lag);
+            debug!(purge_trx_lag = lag, "calculated purge lag");
         }
 
+        self.sample_interval_seconds.set(sample_interval_seconds);
+
         // Set active transactions
         self.trx_active_transactions.set(active_trx);
+        self.trx_oldest_seconds.set(oldest_trx_seconds);
         debug!(
             active_transactions = active_trx,
+            oldest_transaction_seconds = oldest_trx_seconds,
             "counted active transactions"
         );
 
@@ -286,6 +690,7 @@ impl StatusParser {
         self.semaphore_waits.set(semaphore_waits);
         #[allow(clippy::cast_possible_truncation)]
         self.semaphore_wait_time_ms.set(semaphore_wait_time_ms as i64);
+        self.semaphore_wait_time_seconds.set(semaphore_wait_time_ms / 1000.0);
         debug!(
             semaphore_waits_total = semaphore_waits,
             semaphore_wait_time_total_ms = semaphore_wait_time_ms,
@@ -294,6 +699,187 @@ impl StatusParser {
 
         Ok(())
     }
+
+    /// Redo bytes written per second since the previous scrape, from the LSN delta, and set
+    /// it as `mariadb_innodb_redo_write_bytes_per_second`. `None` on the first scrape, when
+    /// there's nothing to compare with yet.
+    fn measure_redo_write_rate(&self, lsn: i64, now: Instant) -> Option<f64> {
+        let Ok(mut last) = self.last_lsn.lock() else {
+            return None;
+        };
+        let (then, previous_lsn) = last.replace((now, lsn))?;
+
+        let elapsed = now.saturating_duration_since(then).as_secs_f64();
+        let written = lsn - previous_lsn;
+
+        // A lower LSN means a different server (DSN reload); start over from this sample
+        if written < 0 || elapsed <= 0.0 {
+            return None;
+        }
+
+        let rate = i64_to_f64(written) / elapsed;
+        self.redo_write_rate.set(rate);
+        debug!(redo_write_rate = rate, "measured redo write rate");
+
+        Some(rate)
+    }
+
+    /// Estimate the time since the last checkpoint.
+    ///
+    /// The status output has no checkpoint timestamp, only LSNs, so the checkpoint age in
+    /// bytes is divided by the redo write rate (LSN bytes per second) observed since the
+    /// previous scrape. This assumes the write rate was steady since the checkpoint; bursts
+    /// make it an over- or underestimate. The gauge stays unchanged on the first scrape and
+    /// while no redo is written with the checkpoint behind, when the rate can't be measured.
+    fn set_seconds_since_checkpoint(&self, checkpoint_age: i64, rate: Option<f64>) {
+        if checkpoint_age <= 0 {
+            self.seconds_since_checkpoint.set(0.0);
+            return;
+        }
+
+        if let Some(rate) = rate.filter(|rate| *rate > 0.0) {
+            let seconds = i64_to_f64(checkpoint_age) / rate;
+            self.seconds_since_checkpoint.set(seconds);
+            debug!(
+                seconds_since_checkpoint = seconds,
+                "estimated seconds since checkpoint"
+            );
+        }
+    }
+
+    /// Split the `OS waits` of the SEMAPHORES section by lock type.
+    fn record_os_waits(&self, line: &str, waits: i64) {
+        if line.starts_with("Mutex spin waits") {
+            self.mutex_os_waits.set(waits);
+        } else if line.starts_with("RW-shared spins") {
+            self.rw_shared_os_waits.set(waits);
+        } else if line.starts_with("RW-excl spins") {
+            self.rw_excl_os_waits.set(waits);
+        }
+    }
+
+    /// Record one `Hash table size` line, numbered by its position in the status output.
+    fn record_hash_partition(&self, partition: usize, size: i64, buffers: i64) {
+        let partition = partition.to_string();
+        self.adaptive_hash_table_size
+            .with_label_values(&[partition.as_str()])
+            .set(size);
+        self.adaptive_hash_node_heap_buffers
+            .with_label_values(&[partition.as_str()])
+            .set(buffers);
+    }
+
+    /// Parse the adaptive hash index line.
+    ///
+    /// Example: "123456 hash searches/s, 12345 non-hash searches/s"
+    fn parse_adaptive_hash(&self, line: &str) {
+        let parts: Vec<&str> = line.split(',').collect();
+        if let Some(hash_part) = parts.first()
+            && let Some(value) = hash_part.split_whitespace().next()
+            && let Ok(searches) = value.parse::<i64>()
+        {
+            self.adaptive_hash_searches.set(searches);
+            debug!(
+                adaptive_hash_searches = searches,
+                "parsed adaptive hash searches"
+            );
+        }
+        if let Some(btree_part) = parts.get(1)
+            && let Some(value) = btree_part.split_whitespace().next()
+            && let Ok(searches) = value.parse::<i64>()
+        {
+            self.adaptive_hash_searches_btree.set(searches);
+            debug!(
+                adaptive_hash_searches_btree = searches,
+                "parsed adaptive hash B-tree searches"
+            );
+        }
+    }
+}
+
+/// Extract `N` from a `---TRANSACTION 123456, ACTIVE N sec` line.
+fn transaction_active_seconds(line: &str) -> Option<i64> {
+    line.split("ACTIVE")
+        .nth(1)?
+        .split_whitespace()
+        .next()?
+        .parse::<i64>()
+        .ok()
+}
+
+/// Extract the purged transaction id from a
+/// `Purge done for trx's n:o < 1234500 undo n:o < 0 state: running` line.
+fn purge_done_trx_id(line: &str) -> Option<i64> {
+    line.strip_prefix("Purge done for trx's n:o <")?
+        .split_whitespace()
+        .next()?
+        .parse::<i64>()
+        .ok()
+}
+
+/// Extract the table size and node heap buffers from a
+/// `Hash table size 34679, node heap has 2 buffer(s)` line. Some versions add
+/// `used cells N` in between.
+fn hash_table_partition(line: &str) -> Option<(i64, i64)> {
+    let rest = line.strip_prefix("Hash table size")?;
+    let size = rest
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .find(|s| !s.is_empty())?
+        .parse::<i64>()
+        .ok()?;
+    let buffers = rest
+        .split("node heap has")
+        .nth(1)?
+        .split_whitespace()
+        .next()?
+        .parse::<i64>()
+        .ok()?;
+    Some((size, buffers))
+}
+
+/// Extract size, free list length and merges from an
+/// `Ibuf: size 1, free list len 0, seg size 2, 0 merges` line.
+fn ibuf_line(line: &str) -> Option<(i64, i64, i64)> {
+    let rest = line.strip_prefix("Ibuf:")?;
+    let field = |name: &str| {
+        rest.split(',')
+            .map(str::trim)
+            .find_map(|part| part.strip_prefix(name))?
+            .trim()
+            .parse::<i64>()
+            .ok()
+    };
+    let merges = rest
+        .split(',')
+        .map(str::trim)
+        .find_map(|part| part.strip_suffix("merges"))?
+        .trim()
+        .parse::<i64>()
+        .ok()?;
+    Some((field("size ")?, field("free list len ")?, merges))
+}
+
+/// Extract both counters from a `Pages made young 2213, not young 0` line.
+fn pages_made_young_line(line: &str) -> Option<(i64, i64)> {
+    let (young, not_young) = line
+        .strip_prefix("Pages made young")?
+        .split_once(", not young")?;
+    Some((
+        young.trim().parse::<i64>().ok()?,
+        not_young.trim().parse::<i64>().ok()?,
+    ))
+}
+
+/// Total redo log size in bytes, or `None` if the server doesn't report it.
+fn redo_log_capacity(vars: &HashMap<String, String>) -> Option<i64> {
+    let int = |key: &str| vars.get(key).and_then(|v| v.trim().parse::<i64>().ok());
+
+    let capacity = int("innodb_redo_log_capacity").or_else(|| {
+        let files = int("innodb_log_files_in_group").unwrap_or(1);
+        int("innodb_log_file_size")?.checked_mul(files)
+    })?;
+
+    (capacity > 0).then_some(capacity)
 }
 
 impl Default for StatusParser {
@@ -305,6 +891,8 @@ impl Default for StatusParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prometheus::core::Collector as _;
+    use std::time::Duration;
 
     #[test]
     #[allow(clippy::unwrap_used)]
@@ -343,6 +931,50 @@ Last checkpoint at           123450000
         assert_eq!(parser.trx_active_transactions.get(), 3);
     }
 
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_purge_trx_lag() {
+        let parser = StatusParser::new();
+        let status = "
+------------
+TRANSACTIONS
+------------
+Trx id counter 1234567
+Purge done for trx's n:o < 1234500 undo n:o < 0 state: running but idle
+History list length 42
+LIST OF TRANSACTIONS FOR EACH SESSION:
+        ";
+
+        parser.parse(status).unwrap();
+
+        assert_eq!(parser.purge_trx_lag.get(), 67);
+        assert_eq!(
+            purge_done_trx_id("Purge done for trx's n:o < 9 undo n:o < 0 state: running"),
+            Some(9)
+        );
+        assert_eq!(purge_done_trx_id("History list length 42"), None);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_oldest_transaction_seconds() {
+        let parser = StatusParser::new();
+        let status = "
+---TRANSACTION 421234567890, ACTIVE 5 sec starting index read
+---TRANSACTION 421234567891, ACTIVE 1234 sec
+---TRANSACTION 421234567892, ACTIVE 2 sec inserting
+---TRANSACTION 421234567893, not started
+        ";
+
+        parser.parse(status).unwrap();
+
+        assert_eq!(parser.trx_oldest_seconds.get(), 1234);
+
+        // No active transactions left: the gauge drops back to 0
+        parser.parse("---TRANSACTION 421234567893, not started").unwrap();
+        assert_eq!(parser.trx_oldest_seconds.get(), 0);
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)]
     fn test_parse_semaphore_waits() {
@@ -358,8 +990,61 @@ RW-shared spins 54321, rounds 98765, OS waits 456
 
         // Should capture the sum of all OS waits values (123 + 456 = 579)
         assert_eq!(parser.semaphore_waits.get(), 579);
+        // And each lock type separately
+        assert_eq!(parser.mutex_os_waits.get(), 123);
+        assert_eq!(parser.rw_shared_os_waits.get(), 456);
         // Should capture the sum of all wait times (5.00 + 1.25 = 6.25 seconds = 6250 ms)
         assert_eq!(parser.semaphore_wait_time_ms.get(), 6250);
+        assert!((parser.semaphore_wait_time_seconds.get() - 6.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_semaphore_os_waits_by_lock_type() {
+        let parser = StatusParser::new();
+        let status = "
+----------
+SEMAPHORES
+----------
+OS WAIT ARRAY INFO: reservation count 1234
+OS WAIT ARRAY INFO: signal count 1100
+RW-shared spins 0, rounds 4321, OS waits 210
+RW-excl spins 0, rounds 876, OS waits 35
+Mutex spin waits 5000, rounds 9000, OS waits 77
+Spin rounds per wait: 4321.00 RW-shared, 876.00 RW-excl, 0.00 RW-sx
+        ";
+
+        parser.parse(status).unwrap();
+
+        assert_eq!(parser.rw_shared_os_waits.get(), 210);
+        assert_eq!(parser.rw_excl_os_waits.get(), 35);
+        assert_eq!(parser.mutex_os_waits.get(), 77);
+        assert_eq!(parser.semaphore_waits.get(), 210 + 35 + 77);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_change_buffer() {
+        let parser = StatusParser::new();
+        let status = "
+-------------------------------------
+INSERT BUFFER AND ADAPTIVE HASH INDEX
+-------------------------------------
+Ibuf: size 12, free list len 3416, seg size 3429, 8714 merges
+merged operations:
+ insert 10340, delete mark 0, delete 0
+        ";
+
+        parser.parse(status).unwrap();
+
+        assert_eq!(parser.change_buffer_size.get(), 12);
+        assert_eq!(parser.change_buffer_free_list_len.get(), 3_416);
+        assert_eq!(parser.change_buffer_merges.get(), 8_714);
+        assert_eq!(
+            ibuf_line("Ibuf: size 1, free list len 0, seg size 2, 0 merges"),
+            Some((1, 0, 0))
+        );
+        assert_eq!(ibuf_line("Ibuf: size 1"), None);
     }
 
     #[test]
@@ -376,6 +1061,110 @@ RW-shared spins 54321, rounds 98765, OS waits 456
         assert_eq!(parser.adaptive_hash_searches_btree.get(), 12_345);
     }
 
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_adaptive_hash_partitions() {
+        let parser = StatusParser::new();
+        let status = "
+-------------------------------------
+INSERT BUFFER AND ADAPTIVE HASH INDEX
+-------------------------------------
+Ibuf: size 1, free list len 0, seg size 2, 0 merges
+merged operations:
+ insert 0, delete mark 0, delete 0
+discarded operations:
+ insert 0, delete mark 0, delete 0
+Hash table size 34679, node heap has 2 buffer(s)
+Hash table size 34679, node heap has 0 buffer(s)
+Hash table size 34679, used cells 12, node heap has 5 buffer(s)
+Hash table size 34679, node heap has 1 buffer(s)
+0 hash searches/s, 0 non-hash searches/s
+        ";
+
+        parser.parse(status).unwrap();
+
+        let series: usize = parser
+            .adaptive_hash_table_size
+            .collect()
+            .iter()
+            .map(|mf| mf.get_metric().len())
+            .sum();
+        assert_eq!(series, 4);
+        assert_eq!(
+            parser
+                .adaptive_hash_table_size
+                .with_label_values(&["2"])
+                .get(),
+            34_679
+        );
+        assert_eq!(
+            parser
+                .adaptive_hash_node_heap_buffers
+                .with_label_values(&["0"])
+                .get(),
+            2
+        );
+        assert_eq!(
+            parser
+                .adaptive_hash_node_heap_buffers
+                .with_label_values(&["2"])
+                .get(),
+            5
+        );
+
+        // A single-partition status replaces the previous partitions
+        parser
+            .parse("Hash table size 1000, node heap has 3 buffer(s)\n")
+            .unwrap();
+        let series: usize = parser
+            .adaptive_hash_node_heap_buffers
+            .collect()
+            .iter()
+            .map(|mf| mf.get_metric().len())
+            .sum();
+        assert_eq!(series, 1);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_buffer_pool_lru() {
+        let parser = StatusParser::new();
+        let status = "
+----------------------
+BUFFER POOL AND MEMORY
+----------------------
+Total large memory allocated 274857984
+Dictionary memory allocated 1037880
+Buffer pool size   16224
+Free buffers       1024
+Database pages     14962
+Old database pages 5503
+Modified db pages  311
+Pending reads 0
+Pending writes: LRU 0, flush list 0, single page 0
+Pages made young 1804224, not young 27371923
+4.99 youngs/s, 0.00 non-youngs/s
+Pages read 1371219, created 52093, written 2466981
+0.00 reads/s, 0.00 creates/s, 0.00 writes/s
+Buffer pool hit rate 1000 / 1000, young-making rate 1 / 1000 not 0 / 1000
+----------------------
+INDIVIDUAL BUFFER POOL INFO
+----------------------
+---BUFFER POOL 0
+Buffer pool size   8112
+Pages made young 902112, not young 13685961
+---BUFFER POOL 1
+Buffer pool size   8112
+Pages made young 902112, not young 13685962
+        ";
+
+        parser.parse(status).unwrap();
+
+        assert_eq!(parser.pages_made_young.get(), 1_804_224);
+        assert_eq!(parser.pages_made_not_young.get(), 27_371_923);
+        assert_eq!(pages_made_young_line("Pages made young 1, not young"), None);
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)]
     fn test_parse_empty_status() {
@@ -385,4 +1174,126 @@ RW-shared spins 54321, rounds 98765, OS waits 456
         // Should not panic on empty input
         parser.parse(status).unwrap();
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_parse_sample_interval() {
+        let parser = StatusParser::new();
+        let status = "
+=====================================
+2024-12-02 06:30:00 0x7f8b8c000700 INNODB MONITOR OUTPUT
+=====================================
+Per second averages calculated from the last 18 seconds
+-----------------
+BACKGROUND THREAD
+-----------------
+srv_master_thread loops: 12 srv_active, 0 srv_shutdown, 3456 srv_idle
+-------------------------------------
+INSERT BUFFER AND ADAPTIVE HASH INDEX
+-------------------------------------
+1024.50 hash searches/s, 312.25 non-hash searches/s
+        ";
+
+        parser.parse(status).unwrap();
+
+        assert_eq!(parser.sample_interval_seconds.get(), 18);
+
+        // A snapshot without the header doesn't keep the previous window
+        parser
+            .parse("Log sequence number          123456789")
+            .unwrap();
+        assert_eq!(parser.sample_interval_seconds.get(), 0);
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_redo_log_capacity() {
+        // MariaDB 10.6+: single log file, no innodb_log_files_in_group
+        assert_eq!(
+            redo_log_capacity(&vars(&[("innodb_log_file_size", "100663296")])),
+            Some(100_663_296)
+        );
+        // Older servers with several log files
+        assert_eq!(
+            redo_log_capacity(&vars(&[
+                ("innodb_log_file_size", "50331648"),
+                ("innodb_log_files_in_group", "2"),
+            ])),
+            Some(100_663_296)
+        );
+        // MySQL 8.0.30+
+        assert_eq!(
+            redo_log_capacity(&vars(&[
+                ("innodb_log_file_size", "50331648"),
+                ("innodb_log_files_in_group", "2"),
+                ("innodb_redo_log_capacity", "104857600"),
+            ])),
+            Some(104_857_600)
+        );
+        assert_eq!(redo_log_capacity(&vars(&[])), None);
+        assert_eq!(
+            redo_log_capacity(&vars(&[("innodb_log_file_size", "0")])),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_checkpoint_age_ratio() {
+        let parser = StatusParser::new();
+        parser
+            .parse("Log sequence number 1075000\nLast checkpoint at 1000000\n")
+            .unwrap();
+
+        parser.set_checkpoint_age_ratio(100_000);
+        assert!((parser.checkpoint_age_ratio().get() - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_seconds_since_checkpoint() {
+        let parser = StatusParser::new();
+        let start = Instant::now();
+        let record = |lsn: i64, age: i64, now: Instant| {
+            let rate = parser.measure_redo_write_rate(lsn, now);
+            parser.set_seconds_since_checkpoint(age, rate);
+        };
+
+        // No rate yet
+        record(1_000_000, 50_000, start);
+        assert!(parser.seconds_since_checkpoint().get().abs() < f64::EPSILON);
+
+        // 100_000 bytes in 10s is 10_000 bytes/s, so 50_000 bytes behind is 5s
+        record(1_100_000, 50_000, start + Duration::from_secs(10));
+        assert!((parser.seconds_since_checkpoint().get() - 5.0).abs() < f64::EPSILON);
+        assert!((parser.redo_write_rate().get() - 10_000.0).abs() < f64::EPSILON);
+
+        // Idle with the checkpoint behind: the rate is unknown, keep the last estimate
+        record(1_100_000, 50_000, start + Duration::from_secs(20));
+        assert!((parser.seconds_since_checkpoint().get() - 5.0).abs() < f64::EPSILON);
+
+        // Checkpoint caught up
+        record(1_100_000, 0, start + Duration::from_secs(30));
+        assert!(parser.seconds_since_checkpoint().get().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_redo_log_write_rate_ratio() {
+        let parser = StatusParser::new();
+        let start = Instant::now();
+
+        parser.measure_redo_write_rate(0, start);
+        parser.set_redo_log_write_rate_ratio(72_000_000);
+        assert!(parser.redo_log_write_rate_ratio().get().abs() < f64::EPSILON);
+
+        // 10_000 bytes/s is 36_000_000 bytes an hour, half of the redo log
+        parser.measure_redo_write_rate(100_000, start + Duration::from_secs(10));
+        parser.set_redo_log_write_rate_ratio(72_000_000);
+        assert!((parser.redo_log_write_rate_ratio().get() - 0.5).abs() < f64::EPSILON);
+    }
 }
@@ -0,0 +1,131 @@
+use crate::collectors::util::metric_name;
+use anyhow::Result;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// `MariaDB` exposes `INNODB_SYS_TABLESPACES`; `MySQL` 8 renamed it to `INNODB_TABLESPACES`.
+/// Limited to the 20 largest files, like the schema tables collector.
+const TABLESPACE_QUERY_CANDIDATES: &[&str] = &[
+    "SELECT CAST(SPACE AS UNSIGNED), NAME,
+            CAST(COALESCE(FILE_SIZE,0) AS UNSIGNED),
+            CAST(COALESCE(ALLOCATED_SIZE,0) AS UNSIGNED)
+     FROM information_schema.INNODB_SYS_TABLESPACES
+     ORDER BY FILE_SIZE DESC
+     LIMIT 20",
+    "SELECT CAST(SPACE AS UNSIGNED), NAME,
+            CAST(COALESCE(FILE_SIZE,0) AS UNSIGNED),
+            CAST(COALESCE(ALLOCATED_SIZE,0) AS UNSIGNED)
+     FROM information_schema.INNODB_TABLESPACES
+     ORDER BY FILE_SIZE DESC
+     LIMIT 20",
+];
+
+/// Physical on-disk size of `InnoDB` tablespace files.
+///
+/// Complements `mariadb_info_schema_table_size_bytes`, which is the logical data+index size.
+#[derive(Clone)]
+pub struct TablespacesCollector {
+    file_size_bytes: IntGaugeVec,
+    allocated_size_bytes: IntGaugeVec,
+}
+
+impl TablespacesCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new tablespaces collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        let file_size_bytes = IntGaugeVec::new(
+            Opts::new(
+                metric_name("innodb_tablespace_file_size_bytes"),
+                "Apparent size of the tablespace file in bytes",
+            ),
+            &["space", "name"],
+        )
+        .expect("valid mariadb_innodb_tablespace_file_size_bytes metric");
+
+        let allocated_size_bytes = IntGaugeVec::new(
+            Opts::new(
+                metric_name("innodb_tablespace_allocated_size_bytes"),
+                "Bytes actually allocated on disk for the tablespace file",
+            ),
+            &["space", "name"],
+        )
+        .expect("valid mariadb_innodb_tablespace_allocated_size_bytes metric");
+
+        Self {
+            file_size_bytes,
+            allocated_size_bytes,
+        }
+    }
+
+    /// Get the file size metric for registration.
+    #[must_use]
+    pub const fn file_size_bytes(&self) -> &IntGaugeVec {
+        &self.file_size_bytes
+    }
+
+    /// Get the allocated size metric for registration.
+    #[must_use]
+    pub const fn allocated_size_bytes(&self) -> &IntGaugeVec {
+        &self.allocated_size_bytes
+    }
+
+    /// Collect per-tablespace file sizes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails (though queries are best-effort).
+    #[instrument(skip(self, pool), level = "debug", fields(sub_collector = "tablespaces"))]
+    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        self.file_size_bytes.reset();
+        self.allocated_size_bytes.reset();
+
+        for query in TABLESPACE_QUERY_CANDIDATES {
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = *query,
+                otel.kind = "client"
+            );
+
+            match sqlx::query_as::<_, (u64, String, u64, u64)>(*query)
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => {
+                    for (space, name, file_size, allocated_size) in rows {
+                        let space = space.to_string();
+                        let labels = [space.as_str(), name.as_str()];
+                        self.file_size_bytes
+                            .with_label_values(&labels)
+                            .set(i64::try_from(file_size).unwrap_or(i64::MAX));
+                        self.allocated_size_bytes
+                            .with_label_values(&labels)
+                            .set(i64::try_from(allocated_size).unwrap_or(i64::MAX));
+                    }
+                    return Ok(());
+                }
+                Err(e) => {
+                    debug!(query, error = %e, "tablespace query form not supported");
+                }
+            }
+        }
+
+        debug!("InnoDB tablespace information not available; skipping");
+        Ok(())
+    }
+}
+
+impl Default for TablespacesCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,215 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{SYSTEM_SCHEMAS, database_filter_sql, is_access_denied, metric_name, series_limit},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Tables exported per scrape, by modified counter, to bound cardinality
+/// (overridden by `--max-series-per-collector`).
+const TOP_TABLES: usize = 25;
+
+/// `NAME` is `schema/table`.
+const SCHEMA_EXPR: &str = "SUBSTRING_INDEX(NAME, '/', 1)";
+
+/// Rows modified since the last statistics update, per table, from
+/// `information_schema.INNODB_SYS_TABLESTATS` (opt-in, requires `PROCESS`).
+///
+/// `InnoDB` recalculates statistics once a table has changed enough (or on `ANALYZE TABLE`),
+/// resetting `MODIFIED_COUNTER`; a counter far above the row estimate means the optimizer is
+/// planning with stale statistics. Limited to the tables with the most modifications outside
+/// the system schemas and `--exclude-databases` (and inside `--include-databases` when set).
+#[derive(Clone)]
+pub struct InnodbTablestatsCollector {
+    modified_counter: IntGaugeVec,
+    rows: IntGaugeVec,
+}
+
+impl InnodbTablestatsCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new `InnoDB` table statistics collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            modified_counter: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("innodb_table_modified_counter"),
+                    "Rows modified since InnoDB last updated the table statistics (INNODB_SYS_TABLESTATS.MODIFIED_COUNTER)",
+                ),
+                &["schema", "table"],
+            )
+            .expect("valid mariadb_innodb_table_modified_counter metric"),
+            rows: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("innodb_table_stats_rows"),
+                    "Row estimate from the last table statistics update (INNODB_SYS_TABLESTATS.NUM_ROWS)",
+                ),
+                &["schema", "table"],
+            )
+            .expect("valid mariadb_innodb_table_stats_rows metric"),
+        }
+    }
+
+    fn set_table(&self, schema: &str, table: &str, modified: u64, rows: u64) {
+        self.modified_counter
+            .with_label_values(&[schema, table])
+            .set(i64::try_from(modified).unwrap_or(i64::MAX));
+        self.rows
+            .with_label_values(&[schema, table])
+            .set(i64::try_from(rows).unwrap_or(i64::MAX));
+    }
+}
+
+impl Default for InnodbTablestatsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for InnodbTablestatsCollector {
+    fn name(&self) -> &'static str {
+        "innodb_tablestats"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "innodb_tablestats")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.modified_counter.clone()))?;
+        registry.register(Box::new(self.rows.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "innodb_tablestats", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // The top tables change between scrapes; drop the ones that fell out
+            self.modified_counter.reset();
+            self.rows.reset();
+
+            // Not every server version ships the table
+            let exists_span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "check INNODB_SYS_TABLESTATS table",
+                otel.kind = "client"
+            );
+
+            let has_table = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema='information_schema' AND table_name='INNODB_SYS_TABLESTATS'",
+            )
+            .fetch_one(pool)
+            .instrument(exists_span)
+            .await
+            .unwrap_or(0)
+                > 0;
+
+            if !has_table {
+                debug!("INNODB_SYS_TABLESTATS not available; skipping collection");
+                return Ok(());
+            }
+
+            let system = SYSTEM_SCHEMAS
+                .iter()
+                .map(|s| format!("'{s}'"))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            // --include-databases / --exclude-databases, bound as parameters
+            let (filter, binds) = database_filter_sql(SCHEMA_EXPR);
+
+            let query = format!(
+                "SELECT {SCHEMA_EXPR}, SUBSTRING_INDEX(NAME, '/', -1),
+                        CAST(MODIFIED_COUNTER AS UNSIGNED), CAST(NUM_ROWS AS UNSIGNED)
+                 FROM information_schema.INNODB_SYS_TABLESTATS
+                 WHERE MODIFIED_COUNTER > 0 AND {SCHEMA_EXPR} NOT IN ({system}){filter}
+                 ORDER BY MODIFIED_COUNTER DESC
+                 LIMIT ?"
+            );
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "top InnoDB tables by modified counter",
+                otel.kind = "client"
+            );
+
+            let rows = match binds
+                .into_iter()
+                .fold(
+                    sqlx::query_as::<_, (String, String, u64, u64)>(sqlx::AssertSqlSafe(query)),
+                    sqlx::query::QueryAs::bind,
+                )
+                .bind(u64::try_from(series_limit(TOP_TABLES)).unwrap_or(u64::MAX))
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => {
+                    debug!(error = %e, "INNODB_SYS_TABLESTATS query failed; skipping");
+                    return Ok(());
+                }
+            };
+
+            for (schema, table, modified, num_rows) in rows {
+                self.set_table(&schema, &table, modified, num_rows);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn required_privileges(&self) -> &[&str] {
+        &["PROCESS"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_table() {
+        let collector = InnodbTablestatsCollector::new();
+        collector.set_table("shop", "orders", 48_000, 1_200);
+        collector.set_table("shop", "events", u64::MAX, 0);
+
+        assert_eq!(
+            collector
+                .modified_counter
+                .with_label_values(&["shop", "orders"])
+                .get(),
+            48_000
+        );
+        assert_eq!(
+            collector.rows.with_label_values(&["shop", "orders"]).get(),
+            1_200
+        );
+        assert_eq!(
+            collector
+                .modified_counter
+                .with_label_values(&["shop", "events"])
+                .get(),
+            i64::MAX
+        );
+    }
+}
@@ -1,9 +1,13 @@
+use crate::collectors::util::{is_mariadb_version_below, metric_name};
 use anyhow::Result;
 use prometheus::IntGauge;
 use sqlx::MySqlPool;
 use tracing::{info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// `performance_schema.metadata_locks` was added in `MariaDB` 10.5.2.
+const MIN_VERSION: i32 = 100_502;
+
 /// Collector for metadata locks from `performance_schema`.
 #[derive(Clone)]
 pub struct MetadataLocksCollector {
@@ -21,7 +25,7 @@ impl MetadataLocksCollector {
     pub fn new() -> Self {
         Self {
             lock_count: IntGauge::new(
-                "mariadb_perf_schema_metadata_lock_count",
+                metric_name("perf_schema_metadata_lock_count"),
                 "Number of metadata locks currently listed",
             )
             .expect("valid mariadb_perf_schema_metadata_lock_count metric"),
@@ -41,6 +45,11 @@ impl MetadataLocksCollector {
     /// Returns an error if the database query fails (though queries are best-effort).
     #[instrument(skip(self, pool), level = "debug", fields(sub_collector = "metadata_locks"))]
     pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        if is_mariadb_version_below(MIN_VERSION) {
+            tracing::debug!("performance_schema.metadata_locks requires MariaDB 10.5.2+; skipping");
+            return Ok(());
+        }
+
         let span = info_span!(
             "db.query",
             db.system = "mysql",
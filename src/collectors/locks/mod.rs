@@ -1,7 +1,6 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use tracing::instrument;
 
@@ -46,7 +45,7 @@ impl Collector for LocksCollector {
         err,
         fields(collector = "locks")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.metadata_locks.lock_count().clone()))?;
         registry.register(Box::new(self.table_lock_waits.lock_waits().clone()))?;
         Ok(())
@@ -1,3 +1,4 @@
+use crate::collectors::util::metric_name;
 use anyhow::Result;
 use prometheus::IntGauge;
 use sqlx::MySqlPool;
@@ -21,7 +22,7 @@ impl TableLockWaitsCollector {
     pub fn new() -> Self {
         Self {
             lock_waits: IntGauge::new(
-                "mariadb_perf_schema_table_lock_waits",
+                metric_name("perf_schema_table_lock_waits"),
                 "Number of table lock waits observed (performance_schema)",
             )
             .expect("valid mariadb_perf_schema_table_lock_waits metric"),
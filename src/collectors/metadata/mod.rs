@@ -1,11 +1,17 @@
-use crate::collectors::Collector;
+use crate::collectors::{
+    Collector, Registrar,
+    util::{is_access_denied, is_mariadb_version_below, metric_name},
+};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::{IntGaugeVec, Opts, Registry};
+use prometheus::{IntGaugeVec, Opts};
 use sqlx::MySqlPool;
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// `metadata_lock_info` plugin first shipped in `MariaDB` 10.0.7.
+const MIN_VERSION: i32 = 100_007;
+
 /// Metadata lock info (opt-in; requires `metadata_lock_info` plugin).
 #[derive(Clone)]
 pub struct MetadataCollector {
@@ -24,7 +30,7 @@ impl MetadataCollector {
         Self {
             lock_info_count: IntGaugeVec::new(
                 Opts::new(
-                    "mariadb_metadata_lock_info_count",
+                    metric_name("metadata_lock_info_count"),
                     "Count of metadata locks by mode and type (metadata_lock_info plugin)",
                 ),
                 &["mode", "type"],
@@ -51,7 +57,7 @@ impl Collector for MetadataCollector {
         err,
         fields(collector = "metadata")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.lock_info_count.clone()))?;
         Ok(())
     }
@@ -61,6 +67,11 @@ impl Collector for MetadataCollector {
         Box::pin(async move {
             self.lock_info_count.reset();
 
+            if is_mariadb_version_below(MIN_VERSION) {
+                debug!("metadata_lock_info requires MariaDB 10.0.7+; skipping");
+                return Ok(());
+            }
+
             let exists_span = info_span!(
                 "db.query",
                 db.system = "mysql",
@@ -99,6 +110,7 @@ impl Collector for MetadataCollector {
             .await
             {
                 Ok(r) => r,
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
                 Err(e) => {
                     tracing::error!("Metadata lock info query failed: {}", e);
                     vec![]
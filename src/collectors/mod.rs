@@ -1,4 +1,5 @@
 use anyhow::Result;
+use fixture::StatusSnapshot;
 use futures::future::BoxFuture;
 use prometheus::Registry;
 use sqlx::MySqlPool;
@@ -7,6 +8,23 @@ use std::collections::HashMap;
 #[macro_use]
 mod register_macro;
 
+/// Where collectors register their metrics: a prometheus `Registry`, or the metric catalog
+/// behind `list-metrics`, which also keeps the label vectors that have no series yet.
+pub trait Registrar {
+    /// Register a metric
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metric is invalid or already registered
+    fn register(&self, collector: Box<dyn prometheus::core::Collector>) -> prometheus::Result<()>;
+}
+
+impl Registrar for Registry {
+    fn register(&self, collector: Box<dyn prometheus::core::Collector>) -> prometheus::Result<()> {
+        Self::register(self, collector)
+    }
+}
+
 pub trait Collector {
     fn name(&self) -> &'static str;
 
@@ -15,14 +33,31 @@ pub trait Collector {
     /// # Errors
     ///
     /// Returns an error if metric registration fails
-    fn register_metrics(&self, registry: &Registry) -> Result<()>;
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()>;
 
     // lifetime 'a is needed to tie the future to the lifetime of self and pool
     fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>>;
 
+    /// Update the metrics from a captured `StatusSnapshot` instead of querying the server, so
+    /// the parsing can be tested without a database. Collectors whose data isn't part of a
+    /// snapshot leave their metrics unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot can't be parsed
+    fn collect_from_fixture(&self, _snapshot: &StatusSnapshot) -> Result<()> {
+        Ok(())
+    }
+
     fn enabled_by_default(&self) -> bool {
         false
     }
+
+    /// Global privileges the collector needs (e.g. `PROCESS`), checked against `SHOW GRANTS`
+    /// once at startup so a missing grant is reported instead of silently exporting nothing.
+    fn required_privileges(&self) -> &[&str] {
+        &[]
+    }
 }
 
 // Make utils available to all collectors (exclusions, etc.)
@@ -60,9 +95,30 @@ register_collectors! {
     metadata => MetadataCollector,
     userstat => UserStatCollector,
     innodb => InnodbCollector,
+    galera => GaleraCollector,
+    engines => EnginesCollector,
+    file_io => FileIoCollector,
+    table_io => TableIoCollector,
+    user_connections => UserConnectionsCollector,
+    account_statements => AccountStatementsCollector,
+    host_disk => HostDiskCollector,
+    status_all => StatusAllCollector,
+    innodb_tablestats => InnodbTablestatsCollector,
+    perf_schema_setup => PerfSchemaSetupCollector,
+    table_health => TableHealthCollector,
+    innodb_trx => InnodbTrxCollector,
+    sys => SysCollector,
     // Add more collectors here - just follow the same pattern!
 }
 
 // Other modules
+pub mod build_info;
+pub mod catalog;
 pub mod config;
+pub mod fixture;
+pub mod privileges;
 pub mod registry;
+pub mod target_info;
+
+#[cfg(test)]
+pub mod test_support;
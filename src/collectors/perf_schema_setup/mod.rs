@@ -0,0 +1,214 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{is_access_denied, metric_name},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+const CONSUMERS_QUERY: &str = "SELECT NAME, ENABLED FROM performance_schema.setup_consumers";
+
+/// Instruments grouped by their top-level class (`statement`, `stage`, `wait`, `memory`, ...);
+/// there are over a thousand, too many to export one by one.
+const INSTRUMENTS_QUERY: &str = r"
+    SELECT SUBSTRING_INDEX(NAME, '/', 1) AS class,
+           CAST(SUM(ENABLED = 'YES') AS SIGNED),
+           COUNT(*)
+    FROM performance_schema.setup_instruments
+    GROUP BY class";
+
+/// `performance_schema` consumer and instrument setup (opt-in).
+///
+/// With `performance_schema` on (`mariadb_global_variables_performance_schema`), tables such as
+/// `events_statements_summary_by_digest` still stay empty while their consumer or instruments
+/// are disabled, so the `statements`, `locks`, `file_io` and `table_io` collectors report
+/// nothing. This collector shows which parts are actually switched on.
+#[derive(Clone)]
+pub struct PerfSchemaSetupCollector {
+    consumer_enabled: IntGaugeVec,
+    instruments_enabled: IntGaugeVec,
+    instruments: IntGaugeVec,
+}
+
+impl PerfSchemaSetupCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new `performance_schema` setup collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            consumer_enabled: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_consumer_enabled"),
+                    "Whether the performance_schema consumer is enabled (setup_consumers.ENABLED)",
+                ),
+                &["name"],
+            )
+            .expect("valid mariadb_perf_schema_consumer_enabled metric"),
+            instruments_enabled: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_instruments_enabled"),
+                    "Number of enabled performance_schema instruments per class (setup_instruments)",
+                ),
+                &["class"],
+            )
+            .expect("valid mariadb_perf_schema_instruments_enabled metric"),
+            instruments: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_instruments"),
+                    "Number of performance_schema instruments per class (setup_instruments)",
+                ),
+                &["class"],
+            )
+            .expect("valid mariadb_perf_schema_instruments metric"),
+        }
+    }
+
+    fn set_consumer(&self, name: &str, enabled: &str) {
+        self.consumer_enabled
+            .with_label_values(&[name])
+            .set(i64::from(enabled.eq_ignore_ascii_case("YES")));
+    }
+
+    fn set_instrument_class(&self, class: &str, enabled: i64, total: i64) {
+        self.instruments_enabled
+            .with_label_values(&[class])
+            .set(enabled);
+        self.instruments.with_label_values(&[class]).set(total);
+    }
+}
+
+impl Default for PerfSchemaSetupCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for PerfSchemaSetupCollector {
+    fn name(&self) -> &'static str {
+        "perf_schema_setup"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "perf_schema_setup")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.consumer_enabled.clone()))?;
+        registry.register(Box::new(self.instruments_enabled.clone()))?;
+        registry.register(Box::new(self.instruments.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "perf_schema_setup", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.consumer_enabled.reset();
+            self.instruments_enabled.reset();
+            self.instruments.reset();
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = CONSUMERS_QUERY,
+                otel.kind = "client"
+            );
+
+            match sqlx::query_as::<_, (String, String)>(CONSUMERS_QUERY)
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => {
+                    for (name, enabled) in rows {
+                        self.set_consumer(&name, &enabled);
+                    }
+                }
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => {
+                    debug!(error = %e, "setup_consumers query failed; skipping");
+                    return Ok(());
+                }
+            }
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "performance_schema instruments by class",
+                otel.kind = "client"
+            );
+
+            match sqlx::query_as::<_, (String, i64, i64)>(INSTRUMENTS_QUERY)
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => {
+                    for (class, enabled, total) in rows {
+                        self.set_instrument_class(&class, enabled, total);
+                    }
+                }
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => debug!(error = %e, "setup_instruments query failed; skipping"),
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_consumer_and_instrument_class() {
+        let collector = PerfSchemaSetupCollector::new();
+        collector.set_consumer("events_statements_current", "NO");
+        collector.set_consumer("statements_digest", "YES");
+        collector.set_instrument_class("statement", 0, 220);
+
+        assert_eq!(
+            collector
+                .consumer_enabled
+                .with_label_values(&["events_statements_current"])
+                .get(),
+            0
+        );
+        assert_eq!(
+            collector
+                .consumer_enabled
+                .with_label_values(&["statements_digest"])
+                .get(),
+            1
+        );
+        assert_eq!(
+            collector
+                .instruments_enabled
+                .with_label_values(&["statement"])
+                .get(),
+            0
+        );
+        assert_eq!(
+            collector
+                .instruments
+                .with_label_values(&["statement"])
+                .get(),
+            220
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! `SHOW GRANTS` parsing for the startup check of `Collector::required_privileges`.
+
+use crate::collectors::util::{is_mariadb_version_at_least, is_mysql};
+use std::{collections::HashSet, hash::BuildHasher};
+
+/// Privileges that also satisfy a required one: renamed privileges (`REPLICATION CLIENT` is
+/// `BINLOG MONITOR` since 10.5.2, `SLAVE MONITOR` is `REPLICA MONITOR`) and `SUPER`.
+/// `BINLOG MONITOR` does not allow `SHOW SLAVE STATUS`, so it never satisfies
+/// `REPLICA MONITOR`.
+const EQUIVALENT_PRIVILEGES: &[(&str, &[&str])] = &[
+    ("REPLICA MONITOR", &["SLAVE MONITOR", "SUPER"]),
+    ("REPLICATION CLIENT", &["BINLOG MONITOR", "SUPER"]),
+    ("BINLOG MONITOR", &["REPLICATION CLIENT", "SUPER"]),
+];
+
+/// `MariaDB` 10.5 moved `SHOW SLAVE STATUS` from `REPLICATION CLIENT` to `REPLICA MONITOR`;
+/// `MySQL` still requires `REPLICATION CLIENT` for `SHOW REPLICA STATUS`.
+const REPLICA_MONITOR_SPLIT: i32 = 100_500;
+
+/// Global privileges (`ON *.*`) from `SHOW GRANTS` output, uppercased.
+///
+/// Database- and table-level grants are ignored: the privileges collectors declare
+/// (`PROCESS`, `REPLICA MONITOR`, ...) only exist globally.
+#[must_use]
+pub fn global_privileges(grants: &[String]) -> HashSet<String> {
+    grants
+        .iter()
+        .filter_map(|grant| {
+            let rest = grant.trim().strip_prefix("GRANT ")?;
+            let (privileges, _) = rest.split_once(" ON *.* ")?;
+            Some(privileges)
+        })
+        .flat_map(|privileges| privileges.split(','))
+        .map(|privilege| privilege.trim().to_ascii_uppercase())
+        .filter(|privilege| !privilege.is_empty())
+        .collect()
+}
+
+/// Whether `granted` includes `required`, directly, through `ALL PRIVILEGES` or through an
+/// equivalent privilege on the current server's version.
+#[must_use]
+pub fn has_privilege<S: BuildHasher>(granted: &HashSet<String, S>, required: &str) -> bool {
+    let required = required.to_ascii_uppercase();
+
+    granted.contains("ALL PRIVILEGES")
+        || granted.contains(&required)
+        || EQUIVALENT_PRIVILEGES
+            .iter()
+            .filter(|(privilege, _)| *privilege == required)
+            .flat_map(|(_, equivalents)| equivalents.iter())
+            .any(|equivalent| granted.contains(*equivalent))
+        || (required == "REPLICA MONITOR"
+            && granted.contains("REPLICATION CLIENT")
+            && (is_mysql() || !is_mariadb_version_at_least(REPLICA_MONITOR_SPLIT)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collectors::util::{ServerInfo, set_mariadb_version, with_server};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_global_privileges_ignores_database_grants() {
+        let grants = vec![
+            "GRANT PROCESS, REPLICATION CLIENT ON *.* TO `exporter`@`%` IDENTIFIED BY PASSWORD '*ABC'"
+                .to_string(),
+            "GRANT SELECT ON `performance_schema`.* TO `exporter`@`%`".to_string(),
+        ];
+
+        let granted = global_privileges(&grants);
+        assert_eq!(granted.len(), 2);
+        assert!(granted.contains("PROCESS"));
+        assert!(granted.contains("REPLICATION CLIENT"));
+        assert!(!granted.contains("SELECT"));
+    }
+
+    #[tokio::test]
+    async fn test_has_privilege() {
+        with_server(Arc::new(ServerInfo::default()), async {
+            set_mariadb_version(100_406);
+
+            let usage = global_privileges(&["GRANT USAGE ON *.* TO `exporter`@`%`".to_string()]);
+            assert!(!has_privilege(&usage, "PROCESS"));
+            assert!(!has_privilege(&usage, "REPLICA MONITOR"));
+
+            let all = global_privileges(&[
+                "GRANT ALL PRIVILEGES ON *.* TO `root`@`localhost` WITH GRANT OPTION".to_string(),
+            ]);
+            assert!(has_privilege(&all, "PROCESS"));
+
+            let legacy = global_privileges(&[
+                "GRANT REPLICATION CLIENT ON *.* TO `exporter`@`%`".to_string()
+            ]);
+            assert!(has_privilege(&legacy, "replica monitor"));
+            assert!(has_privilege(&legacy, "BINLOG MONITOR"));
+            assert!(!has_privilege(&legacy, "PROCESS"));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_binlog_monitor_does_not_satisfy_replica_monitor() {
+        with_server(Arc::new(ServerInfo::default()), async {
+            set_mariadb_version(101_106);
+
+            // What GRANT REPLICATION CLIENT shows as on MariaDB 10.5.2+
+            let binlog =
+                global_privileges(&["GRANT BINLOG MONITOR ON *.* TO `exporter`@`%`".to_string()]);
+            assert!(!has_privilege(&binlog, "REPLICA MONITOR"));
+            assert!(has_privilege(&binlog, "REPLICATION CLIENT"));
+
+            let legacy = global_privileges(&[
+                "GRANT REPLICATION CLIENT ON *.* TO `exporter`@`%`".to_string()
+            ]);
+            assert!(!has_privilege(&legacy, "REPLICA MONITOR"));
+
+            let monitor = global_privileges(&[
+                "GRANT BINLOG MONITOR, SLAVE MONITOR ON *.* TO `exporter`@`%`".to_string(),
+            ]);
+            assert!(has_privilege(&monitor, "REPLICA MONITOR"));
+        })
+        .await;
+    }
+}
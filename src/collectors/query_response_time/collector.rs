@@ -1,9 +1,13 @@
+use crate::collectors::util::{is_access_denied, is_mariadb_version_below, metric_name};
 use anyhow::Result;
 use prometheus::{IntCounterVec, Opts};
 use sqlx::MySqlPool;
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// `query_response_time` plugin first shipped in `MariaDB` 10.0.4.
+const MIN_VERSION: i32 = 100_004;
+
 /// Query response time plugin metrics (opt-in; skipped if plugin not installed).
 /// Exposes histogram-style buckets: le="0.1" (<=100ms), le="1.0" (<=1s), le="10.0" (<=10s), le="+Inf"
 #[derive(Clone)]
@@ -32,7 +36,7 @@ impl QueryResponseTimeCollector {
         // Create histogram-style _bucket metric with le label
         let response_time_bucket = IntCounterVec::new(
             Opts::new(
-                "mariadb_info_schema_query_response_time_seconds_bucket",
+                metric_name("info_schema_query_response_time_seconds_bucket"),
                 "Cumulative counters for query response time histogram buckets",
             ),
             &["le"],
@@ -42,7 +46,7 @@ impl QueryResponseTimeCollector {
         // Create _count metric (total number of queries)
         let response_time_count = prometheus::IntCounter::with_opts(
             Opts::new(
-                "mariadb_info_schema_query_response_time_seconds_count",
+                metric_name("info_schema_query_response_time_seconds_count"),
                 "Total count of queries tracked",
             ),
         )
@@ -51,7 +55,7 @@ impl QueryResponseTimeCollector {
         // Create _sum metric (total sum of query times)
         let response_time_sum = prometheus::Counter::with_opts(
             Opts::new(
-                "mariadb_info_schema_query_response_time_seconds_sum",
+                metric_name("info_schema_query_response_time_seconds_sum"),
                 "Total sum of query response times in seconds",
             ),
         )
@@ -70,9 +74,15 @@ impl QueryResponseTimeCollector {
     ///
     /// Returns an error if the database query fails.
     #[allow(clippy::similar_names)]
+    #[allow(clippy::too_many_lines)]
     #[allow(clippy::manual_let_else)]
     #[instrument(skip(self, pool), level = "debug", fields(sub_collector = "query_response_time"))]
     pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        if is_mariadb_version_below(MIN_VERSION) {
+            debug!("query_response_time plugin requires MariaDB 10.0.4+; skipping collection");
+            return Ok(());
+        }
+
         // Confirm plugin table exists.
         let exists_span = info_span!(
             "db.query",
@@ -112,6 +122,7 @@ impl QueryResponseTimeCollector {
         .await
         {
             Ok(r) => r,
+            Err(e) if is_access_denied(&e) => return Err(e.into()),
             Err(e) => {
                 tracing::error!("Query response time query failed: {}", e);
                 vec![]
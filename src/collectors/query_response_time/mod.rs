@@ -1,7 +1,6 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use tracing::instrument;
 
@@ -41,7 +40,7 @@ impl Collector for QueryResponseTimeCollector {
         err,
         fields(collector = "query_response_time")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.response_time.response_time_bucket().clone()))?;
         registry.register(Box::new(self.response_time.response_time_count().clone()))?;
         registry.register(Box::new(self.response_time.response_time_sum().clone()))?;
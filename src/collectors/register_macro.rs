@@ -16,6 +16,9 @@ macro_rules! register_collectors {
             $(
                 $collector_type($collector_type),
             )*
+            /// In-memory collector for registry unit tests
+            #[cfg(test)]
+            Static(crate::collectors::test_support::StaticCollector),
         }
 
         // Implement Collector trait for CollectorType enum
@@ -25,14 +28,18 @@ macro_rules! register_collectors {
                     $(
                         CollectorType::$collector_type(c) => c.name(),
                     )*
+                    #[cfg(test)]
+                    CollectorType::Static(c) => c.name(),
                 }
             }
 
-            fn register_metrics(&self, registry: &Registry) -> Result<()> {
+            fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
                 match self {
                     $(
                         CollectorType::$collector_type(c) => c.register_metrics(registry),
                     )*
+                    #[cfg(test)]
+                    CollectorType::Static(c) => c.register_metrics(registry),
                 }
             }
 
@@ -41,6 +48,18 @@ macro_rules! register_collectors {
                     $(
                         CollectorType::$collector_type(c) => c.collect(pool),
                     )*
+                    #[cfg(test)]
+                    CollectorType::Static(c) => c.collect(pool),
+                }
+            }
+
+            fn collect_from_fixture(&self, snapshot: &StatusSnapshot) -> Result<()> {
+                match self {
+                    $(
+                        CollectorType::$collector_type(c) => c.collect_from_fixture(snapshot),
+                    )*
+                    #[cfg(test)]
+                    CollectorType::Static(c) => c.collect_from_fixture(snapshot),
                 }
             }
 
@@ -49,6 +68,18 @@ macro_rules! register_collectors {
                     $(
                         CollectorType::$collector_type(c) => c.enabled_by_default(),
                     )*
+                    #[cfg(test)]
+                    CollectorType::Static(c) => c.enabled_by_default(),
+                }
+            }
+
+            fn required_privileges(&self) -> &[&str] {
+                match self {
+                    $(
+                        CollectorType::$collector_type(c) => c.required_privileges(),
+                    )*
+                    #[cfg(test)]
+                    CollectorType::Static(c) => c.required_privileges(),
                 }
             }
         }
@@ -1,13 +1,22 @@
+use crate::collectors::util::metric_name;
 use anyhow::Result;
-use prometheus::IntGauge;
-use sqlx::MySqlPool;
+use prometheus::{Gauge, IntGauge};
+use sqlx::{MySqlPool, Row};
+use std::collections::HashMap;
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// `binlog_expire_logs_seconds` exists on `MariaDB` 10.6+ and `MySQL` 8.0; older servers only
+/// have `expire_logs_days`.
+const EXPIRE_QUERY: &str = "SHOW GLOBAL VARIABLES WHERE Variable_name IN \
+     ('binlog_expire_logs_seconds', 'expire_logs_days')";
+
 /// Collector for primary binlog metrics (SHOW BINARY LOGS).
 #[derive(Clone)]
 pub struct BinlogCollector {
     binlog_files: IntGauge,
+    binlog_total_bytes: IntGauge,
+    expire_seconds: Gauge,
 }
 
 impl BinlogCollector {
@@ -21,10 +30,20 @@ impl BinlogCollector {
     pub fn new() -> Self {
         Self {
             binlog_files: IntGauge::new(
-                "mariadb_primary_binlog_files",
-                "Number of binlog files on primary (requires binary logging)",
+                metric_name("primary_binlog_files"),
+                "Number of binlog files on primary (requires binary logging, -1 on read-only replicas)",
             )
             .expect("valid mariadb_primary_binlog_files metric"),
+            binlog_total_bytes: IntGauge::new(
+                metric_name("primary_binlog_total_bytes"),
+                "Total size of the binlog files on primary (-1 on read-only replicas)",
+            )
+            .expect("valid mariadb_primary_binlog_total_bytes metric"),
+            expire_seconds: Gauge::new(
+                metric_name("binlog_expire_seconds"),
+                "Binlog retention in seconds (binlog_expire_logs_seconds or expire_logs_days), 0 = never purged",
+            )
+            .expect("valid mariadb_binlog_expire_seconds metric"),
         }
     }
 
@@ -34,6 +53,18 @@ impl BinlogCollector {
         &self.binlog_files
     }
 
+    /// Get binlog total size metric.
+    #[must_use]
+    pub const fn binlog_total_bytes(&self) -> &IntGauge {
+        &self.binlog_total_bytes
+    }
+
+    /// Get binlog retention metric.
+    #[must_use]
+    pub const fn expire_seconds(&self) -> &Gauge {
+        &self.expire_seconds
+    }
+
     /// Collect binlog metrics from SHOW BINARY LOGS.
     ///
     /// # Errors
@@ -54,17 +85,64 @@ impl BinlogCollector {
             .instrument(span)
             .await
         {
-            Ok(rows) => self
-                .binlog_files
-                .set(i64::try_from(rows.len()).unwrap_or(i64::MAX)),
+            Ok(rows) => {
+                self.binlog_files
+                    .set(i64::try_from(rows.len()).unwrap_or(i64::MAX));
+
+                // File_size is BIGINT UNSIGNED; servers without the column count as 0
+                let total = rows
+                    .iter()
+                    .filter_map(|row| row.try_get::<u64, _>("File_size").ok())
+                    .fold(0_u64, u64::saturating_add);
+                self.binlog_total_bytes
+                    .set(i64::try_from(total).unwrap_or(i64::MAX));
+            }
             Err(e) => {
                 debug!(error = %e, "binary logging likely disabled; skipping binlog count");
                 self.binlog_files.set(0);
+                self.binlog_total_bytes.set(0);
             }
         }
 
         Ok(())
     }
+
+    /// Read the binlog retention. Runs on every role, since it is configuration rather than
+    /// binlog contents; left unchanged if the variables can't be read.
+    #[instrument(skip(self, pool), level = "debug", fields(sub_collector = "binlog"))]
+    pub async fn collect_expiry(&self, pool: &MySqlPool) {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SHOW VARIABLES",
+            db.statement = EXPIRE_QUERY,
+            otel.kind = "client"
+        );
+
+        match sqlx::query_as::<_, (String, String)>(EXPIRE_QUERY)
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(rows) => {
+                let vars: HashMap<String, String> = rows
+                    .into_iter()
+                    .map(|(name, value)| (name.to_ascii_lowercase(), value))
+                    .collect();
+                if let Some(seconds) = expire_seconds(&vars) {
+                    self.expire_seconds.set(seconds);
+                }
+            }
+            Err(e) => debug!(error = %e, "failed to query binlog expiry variables"),
+        }
+    }
+
+    /// Mark the binlog count as not collected, for read-only replicas.
+    pub fn skip(&self) {
+        debug!("read-only replica; skipping SHOW BINARY LOGS");
+        self.binlog_files.set(-1);
+        self.binlog_total_bytes.set(-1);
+    }
 }
 
 impl Default for BinlogCollector {
@@ -72,3 +150,62 @@ impl Default for BinlogCollector {
         Self::new()
     }
 }
+
+/// Binlog retention in seconds. `binlog_expire_logs_seconds` wins when set; otherwise
+/// `expire_logs_days` (fractional on `MariaDB`) is converted.
+fn expire_seconds(vars: &HashMap<String, String>) -> Option<f64> {
+    let float = |key: &str| vars.get(key).and_then(|v| v.trim().parse::<f64>().ok());
+
+    let seconds = float("binlog_expire_logs_seconds").filter(|s| *s > 0.0);
+    let days = float("expire_logs_days").map(|d| d * 86_400.0);
+
+    seconds
+        .or(days)
+        .or_else(|| float("binlog_expire_logs_seconds"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_expire_seconds() {
+        // MariaDB 10.6+ keeps both variables in sync
+        assert_eq!(
+            expire_seconds(&vars(&[
+                ("binlog_expire_logs_seconds", "172800"),
+                ("expire_logs_days", "2.000000"),
+            ])),
+            Some(172_800.0)
+        );
+        // Older MariaDB: days only, possibly fractional
+        assert_eq!(
+            expire_seconds(&vars(&[("expire_logs_days", "0.500000")])),
+            Some(43_200.0)
+        );
+        // MySQL 8.0 with expire_logs_days left at 0
+        assert_eq!(
+            expire_seconds(&vars(&[
+                ("binlog_expire_logs_seconds", "2592000"),
+                ("expire_logs_days", "0"),
+            ])),
+            Some(2_592_000.0)
+        );
+        // Never purged
+        assert_eq!(
+            expire_seconds(&vars(&[
+                ("binlog_expire_logs_seconds", "0"),
+                ("expire_logs_days", "0"),
+            ])),
+            Some(0.0)
+        );
+        assert_eq!(expire_seconds(&vars(&[])), None);
+    }
+}
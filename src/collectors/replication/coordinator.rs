@@ -0,0 +1,224 @@
+use crate::collectors::util::{is_mariadb_version_below, is_mysql, metric_name};
+use anyhow::Result;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// `performance_schema` replication tables first shipped in `MariaDB` 10.5.2.
+const MIN_VERSION: i32 = 100_502;
+
+/// Collector for the parallel replication coordinator from `performance_schema`.
+///
+/// `replication_applier_status_by_coordinator` reports whether each channel's coordinator
+/// thread runs. Neither `MariaDB` nor `MySQL` exposes the coordinator's queue there, and only
+/// `MySQL` records apply timestamps (per worker, in `replication_applier_status_by_worker`),
+/// so the last applied transaction time is `MySQL`-only. Unlike `Seconds_Behind_Master` it
+/// doesn't depend on the relay log position: `time() - last_applied` is the apply lag.
+#[derive(Clone)]
+pub struct CoordinatorCollector {
+    running: IntGaugeVec,
+    last_applied: IntGaugeVec,
+}
+
+impl CoordinatorCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new coordinator collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            running: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("replica_coordinator_running"),
+                    "Whether the replication coordinator thread of the channel is running (1) or not (0)",
+                ),
+                &["channel"],
+            )
+            .expect("valid mariadb_replica_coordinator_running metric"),
+            last_applied: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("replica_coordinator_last_applied_timestamp_seconds"),
+                    "Unix time the last transaction of the channel finished applying (MySQL only)",
+                ),
+                &["channel"],
+            )
+            .expect("valid mariadb_replica_coordinator_last_applied_timestamp_seconds metric"),
+        }
+    }
+
+    /// Get coordinator running metric.
+    #[must_use]
+    pub const fn running(&self) -> &IntGaugeVec {
+        &self.running
+    }
+
+    /// Get last applied transaction timestamp metric.
+    #[must_use]
+    pub const fn last_applied(&self) -> &IntGaugeVec {
+        &self.last_applied
+    }
+
+    /// Collect coordinator state from `performance_schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails (though queries are best-effort).
+    #[instrument(
+        skip(self, pool),
+        level = "debug",
+        fields(sub_collector = "coordinator")
+    )]
+    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        self.running.reset();
+        self.last_applied.reset();
+
+        if is_mariadb_version_below(MIN_VERSION) {
+            debug!("replication_applier_status_by_coordinator requires MariaDB 10.5.2+; skipping");
+            return Ok(());
+        }
+
+        let exists_span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "check replication_applier_status_by_coordinator table",
+            otel.kind = "client"
+        );
+
+        let has_table = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema='performance_schema' AND table_name='replication_applier_status_by_coordinator'",
+        )
+        .fetch_one(pool)
+        .instrument(exists_span)
+        .await
+        .unwrap_or(0)
+            > 0;
+
+        if !has_table {
+            debug!("replication_applier_status_by_coordinator not available; skipping");
+            return Ok(());
+        }
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement =
+                "SELECT FROM performance_schema.replication_applier_status_by_coordinator",
+            otel.kind = "client"
+        );
+
+        match sqlx::query_as::<_, (String, String)>(
+            "SELECT CHANNEL_NAME, SERVICE_STATE
+             FROM performance_schema.replication_applier_status_by_coordinator",
+        )
+        .fetch_all(pool)
+        .instrument(span)
+        .await
+        {
+            Ok(rows) => self.set_coordinators(&rows),
+            Err(e) => debug!(error = %e, "coordinator status query failed; skipping"),
+        }
+
+        if is_mysql() {
+            self.collect_last_applied(pool).await;
+        }
+
+        Ok(())
+    }
+
+    async fn collect_last_applied(&self, pool: &MySqlPool) {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT FROM performance_schema.replication_applier_status_by_worker",
+            otel.kind = "client"
+        );
+
+        match sqlx::query_as::<_, (String, Option<i64>)>(
+            "SELECT CHANNEL_NAME,
+                    CAST(UNIX_TIMESTAMP(MAX(LAST_APPLIED_TRANSACTION_END_APPLY_TIMESTAMP)) AS SIGNED)
+             FROM performance_schema.replication_applier_status_by_worker
+             GROUP BY CHANNEL_NAME",
+        )
+        .fetch_all(pool)
+        .instrument(span)
+        .await
+        {
+            Ok(rows) => self.set_last_applied(&rows),
+            Err(e) => debug!(error = %e, "last applied transaction query failed; skipping"),
+        }
+    }
+
+    fn set_coordinators(&self, rows: &[(String, String)]) {
+        for (channel, state) in rows {
+            self.running
+                .with_label_values(&[channel.as_str()])
+                .set(i64::from(state.eq_ignore_ascii_case("ON")));
+        }
+    }
+
+    /// A channel that applied nothing yet reports a zero timestamp; it's left out.
+    fn set_last_applied(&self, rows: &[(String, Option<i64>)]) {
+        for (channel, timestamp) in rows {
+            if let Some(timestamp) = timestamp.filter(|t| *t > 0) {
+                self.last_applied
+                    .with_label_values(&[channel.as_str()])
+                    .set(timestamp);
+            }
+        }
+    }
+}
+
+impl Default for CoordinatorCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::core::Collector as _;
+
+    #[test]
+    fn test_set_coordinators() {
+        let collector = CoordinatorCollector::new();
+
+        collector.set_coordinators(&[
+            (String::new(), "ON".to_string()),
+            ("eu".to_string(), "OFF".to_string()),
+        ]);
+
+        assert_eq!(collector.running.with_label_values(&[""]).get(), 1);
+        assert_eq!(collector.running.with_label_values(&["eu"]).get(), 0);
+    }
+
+    #[test]
+    fn test_set_last_applied_skips_zero() {
+        let collector = CoordinatorCollector::new();
+
+        collector.set_last_applied(&[
+            (String::new(), Some(1_741_943_564)),
+            ("idle".to_string(), Some(0)),
+            ("none".to_string(), None),
+        ]);
+
+        let series: usize = collector
+            .last_applied
+            .collect()
+            .iter()
+            .map(|mf| mf.get_metric().len())
+            .sum();
+        assert_eq!(series, 1);
+        assert_eq!(
+            collector.last_applied.with_label_values(&[""]).get(),
+            1_741_943_564
+        );
+    }
+}
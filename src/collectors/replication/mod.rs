@@ -1,21 +1,34 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use tracing::instrument;
 
 pub mod binlog;
+pub mod coordinator;
+pub mod replica_hosts;
 pub mod replica_status;
+pub mod role;
 
 use binlog::BinlogCollector;
+use coordinator::CoordinatorCollector;
+use replica_hosts::ReplicaHostsCollector;
 use replica_status::ReplicaStatusCollector;
+use role::ServerRole;
 
 /// Additional replication details (opt-in; noop on non-replicas).
+///
+/// The server role is read from `@@read_only` once per scrape: read-only replicas skip
+/// `SHOW BINARY LOGS`, and writable servers skip `SHOW SLAVE STATUS` when
+/// `performance_schema` reports no replication channel. `SHOW SLAVE HOSTS` runs on every
+/// role, since an intermediate replica can have replicas of its own. The parallel replication
+/// coordinator is read from `performance_schema` on `MariaDB` 10.5.2+ and `MySQL`.
 #[derive(Clone)]
 pub struct ReplicationCollector {
     replica_status: ReplicaStatusCollector,
     binlog: BinlogCollector,
+    replica_hosts: ReplicaHostsCollector,
+    coordinator: CoordinatorCollector,
 }
 
 impl ReplicationCollector {
@@ -25,6 +38,8 @@ impl ReplicationCollector {
         Self {
             replica_status: ReplicaStatusCollector::new(),
             binlog: BinlogCollector::new(),
+            replica_hosts: ReplicaHostsCollector::new(),
+            coordinator: CoordinatorCollector::new(),
         }
     }
 }
@@ -46,16 +61,21 @@ impl Collector for ReplicationCollector {
         err,
         fields(collector = "replication")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         // Replica status metrics
         registry.register(Box::new(self.replica_status.relay_log_space().clone()))?;
         registry.register(Box::new(self.replica_status.relay_log_pos().clone()))?;
+        registry.register(Box::new(self.replica_status.read_master_log_pos().clone()))?;
+        registry.register(Box::new(self.replica_status.exec_master_log_pos().clone()))?;
+        registry.register(Box::new(self.replica_status.apply_lag_bytes().clone()))?;
         registry.register(Box::new(self.replica_status.seconds_behind_master().clone()))?;
         registry.register(Box::new(self.replica_status.io_running().clone()))?;
         registry.register(Box::new(self.replica_status.sql_running().clone()))?;
         registry.register(Box::new(self.replica_status.last_io_errno().clone()))?;
         registry.register(Box::new(self.replica_status.last_sql_errno().clone()))?;
         registry.register(Box::new(self.replica_status.master_server_id().clone()))?;
+        registry.register(Box::new(self.replica_status.sql_delay().clone()))?;
+        registry.register(Box::new(self.replica_status.sql_remaining_delay().clone()))?;
         registry.register(Box::new(self.replica_status.replica_configured().clone()))?;
         registry.register(Box::new(
             self.replica_status.relay_log_space_by_channel().clone(),
@@ -63,6 +83,15 @@ impl Collector for ReplicationCollector {
         registry.register(Box::new(
             self.replica_status.relay_log_pos_by_channel().clone(),
         ))?;
+        registry.register(Box::new(
+            self.replica_status.read_master_log_pos_by_channel().clone(),
+        ))?;
+        registry.register(Box::new(
+            self.replica_status.exec_master_log_pos_by_channel().clone(),
+        ))?;
+        registry.register(Box::new(
+            self.replica_status.apply_lag_bytes_by_channel().clone(),
+        ))?;
         registry.register(Box::new(
             self.replica_status
                 .seconds_behind_master_by_channel()
@@ -79,9 +108,30 @@ impl Collector for ReplicationCollector {
         registry.register(Box::new(
             self.replica_status.master_server_id_by_channel().clone(),
         ))?;
+        registry.register(Box::new(
+            self.replica_status.sql_delay_by_channel().clone(),
+        ))?;
+        registry.register(Box::new(
+            self.replica_status.sql_remaining_delay_by_channel().clone(),
+        ))?;
+        registry.register(Box::new(self.replica_status.last_error_info().clone()))?;
+        registry.register(Box::new(
+            self.replica_status.gtid_transactions_behind().clone(),
+        ))?;
+        registry.register(Box::new(self.replica_status.master().clone()))?;
 
         // Binlog metrics
         registry.register(Box::new(self.binlog.binlog_files().clone()))?;
+        registry.register(Box::new(self.binlog.binlog_total_bytes().clone()))?;
+        registry.register(Box::new(self.binlog.expire_seconds().clone()))?;
+
+        // Connected replicas (primary side)
+        registry.register(Box::new(self.replica_hosts.connected_replicas().clone()))?;
+        registry.register(Box::new(self.replica_hosts.replica_info().clone()))?;
+
+        // Parallel replication coordinator (performance_schema)
+        registry.register(Box::new(self.coordinator.running().clone()))?;
+        registry.register(Box::new(self.coordinator.last_applied().clone()))?;
 
         Ok(())
     }
@@ -89,8 +139,19 @@ impl Collector for ReplicationCollector {
     #[instrument(skip(self, pool), level = "info", err, fields(collector = "replication", otel.kind = "internal"))]
     fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
-            self.replica_status.collect(pool).await?;
-            self.binlog.collect(pool).await?;
+            let role = ServerRole::detect(pool).await;
+
+            self.replica_status.collect(pool, role).await?;
+
+            if role == ServerRole::Replica {
+                self.binlog.skip();
+            } else {
+                self.binlog.collect(pool).await?;
+            }
+            self.binlog.collect_expiry(pool).await;
+
+            self.replica_hosts.collect(pool).await?;
+            self.coordinator.collect(pool).await?;
             Ok(())
         })
     }
@@ -98,4 +159,9 @@ impl Collector for ReplicationCollector {
     fn enabled_by_default(&self) -> bool {
         false
     }
+
+    // SHOW SLAVE STATUS; REPLICATION CLIENT before 10.5.9
+    fn required_privileges(&self) -> &[&str] {
+        &["REPLICA MONITOR"]
+    }
 }
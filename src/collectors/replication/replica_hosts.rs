@@ -0,0 +1,166 @@
+use super::replica_status::{parse_i64_from_columns, parse_string_from_columns};
+use crate::collectors::util::metric_name;
+use anyhow::Result;
+use prometheus::{IntGauge, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// `SHOW REPLICA HOSTS` is the 10.5+ spelling; older servers only know `SHOW SLAVE HOSTS`.
+const REPLICA_HOSTS_QUERY_CANDIDATES: &[&str] = &["SHOW SLAVE HOSTS", "SHOW REPLICA HOSTS"];
+
+/// Collector for replicas connected to this server (SHOW SLAVE HOSTS).
+///
+/// The primary-side counterpart to replica lag: a replica that silently disconnects
+/// drops out of the list. Only replicas started with `--report-host` show a host.
+#[derive(Clone)]
+pub struct ReplicaHostsCollector {
+    connected_replicas: IntGauge,
+    replica_info: IntGaugeVec,
+}
+
+impl ReplicaHostsCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new replica hosts collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            connected_replicas: IntGauge::new(
+                metric_name("primary_connected_replicas"),
+                "Number of replicas connected to this server (-1 if SHOW SLAVE HOSTS failed)",
+            )
+            .expect("valid mariadb_primary_connected_replicas metric"),
+            replica_info: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("primary_replica_info"),
+                    "Replica connected to this server (always 1)",
+                ),
+                &["server_id", "host"],
+            )
+            .expect("valid mariadb_primary_replica_info metric"),
+        }
+    }
+
+    /// Get connected replicas metric.
+    #[must_use]
+    pub const fn connected_replicas(&self) -> &IntGauge {
+        &self.connected_replicas
+    }
+
+    /// Get replica info metric.
+    #[must_use]
+    pub const fn replica_info(&self) -> &IntGaugeVec {
+        &self.replica_info
+    }
+
+    /// Collect connected replicas from SHOW SLAVE HOSTS.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails (though queries are best-effort).
+    #[instrument(
+        skip(self, pool),
+        level = "debug",
+        fields(sub_collector = "replica_hosts")
+    )]
+    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        self.replica_info.reset();
+
+        for query in REPLICA_HOSTS_QUERY_CANDIDATES {
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SHOW",
+                db.statement = *query,
+                otel.kind = "client"
+            );
+
+            match sqlx::query(*query).fetch_all(pool).instrument(span).await {
+                Ok(rows) => {
+                    let hosts: Vec<(i64, String)> = rows
+                        .iter()
+                        .filter_map(|row| {
+                            let server_id = parse_i64_from_columns(row, &["Server_id"])?;
+                            let host =
+                                parse_string_from_columns(row, &["Host"]).unwrap_or_default();
+                            Some((server_id, host))
+                        })
+                        .collect();
+                    self.set_hosts(&hosts);
+                    return Ok(());
+                }
+                Err(e) => {
+                    debug!(query, error = %e, "replica hosts query form not supported");
+                }
+            }
+        }
+
+        debug!("could not list connected replicas (requires REPLICATION MASTER ADMIN)");
+        self.connected_replicas.set(-1);
+        Ok(())
+    }
+
+    fn set_hosts(&self, hosts: &[(i64, String)]) {
+        self.connected_replicas
+            .set(i64::try_from(hosts.len()).unwrap_or(i64::MAX));
+
+        for (server_id, host) in hosts {
+            self.replica_info
+                .with_label_values(&[server_id.to_string().as_str(), host.as_str()])
+                .set(1);
+        }
+    }
+}
+
+impl Default for ReplicaHostsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::core::Collector as _;
+
+    fn series(collector: &ReplicaHostsCollector) -> usize {
+        collector
+            .replica_info
+            .collect()
+            .iter()
+            .map(|mf| mf.get_metric().len())
+            .sum()
+    }
+
+    #[test]
+    fn test_set_hosts() {
+        let collector = ReplicaHostsCollector::new();
+
+        collector.set_hosts(&[(2, "replica-a".to_string()), (3, String::new())]);
+        assert_eq!(collector.connected_replicas.get(), 2);
+        assert_eq!(
+            collector
+                .replica_info
+                .with_label_values(&["2", "replica-a"])
+                .get(),
+            1
+        );
+        assert_eq!(series(&collector), 2);
+    }
+
+    #[test]
+    fn test_disconnected_replica_drops_out_after_reset() {
+        let collector = ReplicaHostsCollector::new();
+
+        collector.set_hosts(&[(2, "replica-a".to_string()), (3, "replica-b".to_string())]);
+        collector.replica_info.reset();
+        collector.set_hosts(&[(2, "replica-a".to_string())]);
+
+        assert_eq!(collector.connected_replicas.get(), 1);
+        assert_eq!(series(&collector), 1);
+    }
+}
@@ -2,9 +2,13 @@ use anyhow::{Result, anyhow};
 use prometheus::{IntGauge, IntGaugeVec, Opts};
 use sqlx::mysql::MySqlRow;
 use sqlx::{MySqlPool, Row};
+use std::collections::BTreeMap;
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
-use crate::collectors::util::is_mariadb_version_at_least;
+use crate::collectors::util::{
+    MYSQL_REPLICA_STATUS_QUERIES, is_mariadb_version_at_least, is_mysql, metric_name,
+};
+use super::role::ServerRole;
 
 // Keep query semantics aligned with upstream mysqld_exporter:
 // try old/new forms and lock-free suffixes where supported.
@@ -25,52 +29,81 @@ const REPLICA_STATUS_QUERY_CANDIDATES: &[&str] = &[
 pub struct ReplicaStatusCollector {
     relay_log_space: IntGauge,
     relay_log_pos: IntGauge,
+    read_master_log_pos: IntGauge,
+    exec_master_log_pos: IntGauge,
+    apply_lag_bytes: IntGauge,
     seconds_behind_master: IntGauge,
     io_running: IntGauge,
     sql_running: IntGauge,
     last_io_errno: IntGauge,
     last_sql_errno: IntGauge,
     master_server_id: IntGauge,
+    sql_delay: IntGauge,
+    sql_remaining_delay: IntGauge,
     replica_configured: IntGauge,
     relay_log_space_by_channel: IntGaugeVec,
     relay_log_pos_by_channel: IntGaugeVec,
+    read_master_log_pos_by_channel: IntGaugeVec,
+    exec_master_log_pos_by_channel: IntGaugeVec,
+    apply_lag_bytes_by_channel: IntGaugeVec,
     seconds_behind_master_by_channel: IntGaugeVec,
     io_running_by_channel: IntGaugeVec,
     sql_running_by_channel: IntGaugeVec,
     last_io_errno_by_channel: IntGaugeVec,
     last_sql_errno_by_channel: IntGaugeVec,
     master_server_id_by_channel: IntGaugeVec,
+    sql_delay_by_channel: IntGaugeVec,
+    sql_remaining_delay_by_channel: IntGaugeVec,
+    last_error_info: IntGaugeVec,
+    gtid_transactions_behind: IntGaugeVec,
+    master: IntGaugeVec,
 }
 
+/// Longest error message kept as a label value, to bound series size.
+const MAX_ERROR_LABEL_CHARS: usize = 256;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct ReplicaChannelStatus {
     channel_name: String,
     connection_name: String,
     relay_log_space: i64,
     relay_log_pos: i64,
+    read_master_log_pos: i64,
+    /// `None` when the I/O thread reads a different binlog file than the SQL thread executes
+    apply_lag_bytes: Option<i64>,
     seconds_behind_master: Option<i64>,
     io_running: i64,
     sql_running: i64,
     last_io_errno: i64,
     last_sql_errno: i64,
     master_server_id: i64,
+    master_host: String,
+    master_port: String,
+    sql_delay: Option<i64>,
+    sql_remaining_delay: Option<i64>,
+    last_io_error: String,
+    last_sql_error: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct AggregatedReplicaStatus {
     relay_log_space: i64,
     relay_log_pos: i64,
+    read_master_log_pos: i64,
+    apply_lag_bytes: i64,
     seconds_behind_master: i64,
     io_running: i64,
     sql_running: i64,
     last_io_errno: i64,
     last_sql_errno: i64,
     master_server_id: i64,
+    sql_delay: i64,
+    sql_remaining_delay: i64,
 }
 
 impl ReplicaStatusCollector {
     #[must_use]
-    #[allow(clippy::expect_used)]
+    #[allow(clippy::expect_used, clippy::too_many_lines)]
     /// Create a new replica status collector.
     ///
     /// # Panics
@@ -81,69 +114,132 @@ impl ReplicaStatusCollector {
 
         Self {
             relay_log_space: gauge(
-                "mariadb_replica_relay_log_space_bytes",
+                &metric_name("replica_relay_log_space_bytes"),
                 "Total combined size of relay logs on replica",
             ),
-            relay_log_pos: gauge("mariadb_replica_relay_log_pos", "Current relay log position"),
+            relay_log_pos: gauge(
+                &metric_name("replica_relay_log_pos"),
+                "Current relay log position",
+            ),
+            read_master_log_pos: gauge(
+                &metric_name("replica_read_master_log_pos"),
+                "Position in the source binlog the I/O thread has read up to (Read_Master_Log_Pos)",
+            ),
+            exec_master_log_pos: gauge(
+                &metric_name("replica_exec_master_log_pos"),
+                "Position in the source binlog the SQL thread has executed up to (Exec_Master_Log_Pos)",
+            ),
+            apply_lag_bytes: gauge(
+                &metric_name("replica_apply_lag_bytes"),
+                "Bytes read from the source binlog but not yet executed (-1 = unknown, e.g. reading a newer binlog file)",
+            ),
             seconds_behind_master: gauge(
-                "mariadb_replica_seconds_behind_master_seconds",
+                &metric_name("replica_seconds_behind_master_seconds"),
                 "Seconds behind master (replication lag, -1 = NULL/stopped)",
             ),
             io_running: gauge(
-                "mariadb_replica_io_running",
+                &metric_name("replica_io_running"),
                 "Whether the I/O thread is running (1 = Yes, 0 = No)",
             ),
             sql_running: gauge(
-                "mariadb_replica_sql_running",
+                &metric_name("replica_sql_running"),
                 "Whether the SQL thread is running (1 = Yes, 0 = No)",
             ),
-            last_io_errno: gauge("mariadb_replica_last_io_errno", "Last I/O error code"),
-            last_sql_errno: gauge("mariadb_replica_last_sql_errno", "Last SQL error code"),
-            master_server_id: gauge("mariadb_replica_master_server_id", "Master server ID"),
+            last_io_errno: gauge(&metric_name("replica_last_io_errno"), "Last I/O error code"),
+            last_sql_errno: gauge(&metric_name("replica_last_sql_errno"), "Last SQL error code"),
+            master_server_id: gauge(&metric_name("replica_master_server_id"), "Master server ID"),
+            sql_delay: gauge(
+                &metric_name("replica_sql_delay_seconds"),
+                "Configured replication delay (MASTER_DELAY) in seconds (-1 = unknown)",
+            ),
+            sql_remaining_delay: gauge(
+                &metric_name("replica_sql_remaining_delay_seconds"),
+                "Seconds left before the SQL thread applies the next delayed event (-1 = unknown)",
+            ),
             replica_configured: gauge(
-                "mariadb_replica_configured",
+                &metric_name("replica_configured"),
                 "Replica configured (1 = yes, 0 = no)",
             ),
             relay_log_space_by_channel: gauge_by_channel(
-                "mariadb_replica_relay_log_space_bytes_by_channel",
+                &metric_name("replica_relay_log_space_bytes_by_channel"),
                 "Relay log size by replication channel",
                 channel_labels,
             ),
             relay_log_pos_by_channel: gauge_by_channel(
-                "mariadb_replica_relay_log_pos_by_channel",
+                &metric_name("replica_relay_log_pos_by_channel"),
                 "Relay log execution position by replication channel",
                 channel_labels,
             ),
+            read_master_log_pos_by_channel: gauge_by_channel(
+                &metric_name("replica_read_master_log_pos_by_channel"),
+                "Source binlog position read by the I/O thread, by replication channel",
+                channel_labels,
+            ),
+            exec_master_log_pos_by_channel: gauge_by_channel(
+                &metric_name("replica_exec_master_log_pos_by_channel"),
+                "Source binlog position executed by the SQL thread, by replication channel",
+                channel_labels,
+            ),
+            apply_lag_bytes_by_channel: gauge_by_channel(
+                &metric_name("replica_apply_lag_bytes_by_channel"),
+                "Bytes read but not yet executed, by replication channel (-1 = unknown)",
+                channel_labels,
+            ),
             seconds_behind_master_by_channel: gauge_by_channel(
-                "mariadb_replica_seconds_behind_master_seconds_by_channel",
+                &metric_name("replica_seconds_behind_master_seconds_by_channel"),
                 "Replication lag by channel (-1 = unknown)",
                 channel_labels,
             ),
             io_running_by_channel: gauge_by_channel(
-                "mariadb_replica_io_running_by_channel",
+                &metric_name("replica_io_running_by_channel"),
                 "Whether replica I/O thread is running per channel (1 = Yes, 0 = No)",
                 channel_labels,
             ),
             sql_running_by_channel: gauge_by_channel(
-                "mariadb_replica_sql_running_by_channel",
+                &metric_name("replica_sql_running_by_channel"),
                 "Whether replica SQL thread is running per channel (1 = Yes, 0 = No)",
                 channel_labels,
             ),
             last_io_errno_by_channel: gauge_by_channel(
-                "mariadb_replica_last_io_errno_by_channel",
+                &metric_name("replica_last_io_errno_by_channel"),
                 "Last I/O error code by replication channel",
                 channel_labels,
             ),
             last_sql_errno_by_channel: gauge_by_channel(
-                "mariadb_replica_last_sql_errno_by_channel",
+                &metric_name("replica_last_sql_errno_by_channel"),
                 "Last SQL error code by replication channel",
                 channel_labels,
             ),
             master_server_id_by_channel: gauge_by_channel(
-                "mariadb_replica_master_server_id_by_channel",
+                &metric_name("replica_master_server_id_by_channel"),
                 "Source server id by replication channel",
                 channel_labels,
             ),
+            sql_delay_by_channel: gauge_by_channel(
+                &metric_name("replica_sql_delay_seconds_by_channel"),
+                "Configured replication delay (MASTER_DELAY) by replication channel",
+                channel_labels,
+            ),
+            sql_remaining_delay_by_channel: gauge_by_channel(
+                &metric_name("replica_sql_remaining_delay_seconds_by_channel"),
+                "Seconds left before the next delayed event is applied, by replication channel",
+                channel_labels,
+            ),
+            last_error_info: gauge_by_channel(
+                &metric_name("replica_last_error_info"),
+                "Last I/O and SQL thread error messages by replication channel (\"none\" if empty)",
+                &["channel_name", "connection_name", "io_error", "sql_error"],
+            ),
+            gtid_transactions_behind: gauge_by_channel(
+                &metric_name("replica_gtid_transactions_behind"),
+                "Transactions received (Gtid_IO_Pos) but not yet applied (Gtid_Slave_Pos), per GTID domain",
+                &["domain"],
+            ),
+            master: gauge_by_channel(
+                &metric_name("replica_master"),
+                "Primary the replication channel follows (Master_Host, Master_Port), always 1",
+                &["channel_name", "connection_name", "master_host", "master_port"],
+            ),
         }
     }
 
@@ -159,6 +255,24 @@ impl ReplicaStatusCollector {
         &self.relay_log_pos
     }
 
+    /// Get read master log position metric.
+    #[must_use]
+    pub const fn read_master_log_pos(&self) -> &IntGauge {
+        &self.read_master_log_pos
+    }
+
+    /// Get executed master log position metric.
+    #[must_use]
+    pub const fn exec_master_log_pos(&self) -> &IntGauge {
+        &self.exec_master_log_pos
+    }
+
+    /// Get apply lag in bytes metric.
+    #[must_use]
+    pub const fn apply_lag_bytes(&self) -> &IntGauge {
+        &self.apply_lag_bytes
+    }
+
     /// Get seconds behind master metric.
     #[must_use]
     pub const fn seconds_behind_master(&self) -> &IntGauge {
@@ -195,6 +309,18 @@ impl ReplicaStatusCollector {
         &self.master_server_id
     }
 
+    /// Get SQL delay metric.
+    #[must_use]
+    pub const fn sql_delay(&self) -> &IntGauge {
+        &self.sql_delay
+    }
+
+    /// Get SQL remaining delay metric.
+    #[must_use]
+    pub const fn sql_remaining_delay(&self) -> &IntGauge {
+        &self.sql_remaining_delay
+    }
+
     /// Get replica configured metric.
     #[must_use]
     pub const fn replica_configured(&self) -> &IntGauge {
@@ -213,6 +339,24 @@ impl ReplicaStatusCollector {
         &self.relay_log_pos_by_channel
     }
 
+    /// Get per-channel read master log position metric.
+    #[must_use]
+    pub const fn read_master_log_pos_by_channel(&self) -> &IntGaugeVec {
+        &self.read_master_log_pos_by_channel
+    }
+
+    /// Get per-channel executed master log position metric.
+    #[must_use]
+    pub const fn exec_master_log_pos_by_channel(&self) -> &IntGaugeVec {
+        &self.exec_master_log_pos_by_channel
+    }
+
+    /// Get per-channel apply lag in bytes metric.
+    #[must_use]
+    pub const fn apply_lag_bytes_by_channel(&self) -> &IntGaugeVec {
+        &self.apply_lag_bytes_by_channel
+    }
+
     /// Get per-channel lag metric.
     #[must_use]
     pub const fn seconds_behind_master_by_channel(&self) -> &IntGaugeVec {
@@ -249,39 +393,177 @@ impl ReplicaStatusCollector {
         &self.master_server_id_by_channel
     }
 
+    /// Get per-channel SQL delay metric.
+    #[must_use]
+    pub const fn sql_delay_by_channel(&self) -> &IntGaugeVec {
+        &self.sql_delay_by_channel
+    }
+
+    /// Get per-channel SQL remaining delay metric.
+    #[must_use]
+    pub const fn sql_remaining_delay_by_channel(&self) -> &IntGaugeVec {
+        &self.sql_remaining_delay_by_channel
+    }
+
+    /// Get last error info metric.
+    #[must_use]
+    pub const fn last_error_info(&self) -> &IntGaugeVec {
+        &self.last_error_info
+    }
+
+    /// Get GTID transactions behind metric.
+    #[must_use]
+    pub const fn gtid_transactions_behind(&self) -> &IntGaugeVec {
+        &self.gtid_transactions_behind
+    }
+
+    /// Get replica master metric.
+    #[must_use]
+    pub const fn master(&self) -> &IntGaugeVec {
+        &self.master
+    }
+
     fn clear_replica_metrics(&self) {
         self.relay_log_space.set(0);
         self.relay_log_pos.set(0);
+        self.read_master_log_pos.set(0);
+        self.exec_master_log_pos.set(0);
+        self.apply_lag_bytes.set(-1);
         self.seconds_behind_master.set(-1);
         self.io_running.set(0);
         self.sql_running.set(0);
         self.last_io_errno.set(0);
         self.last_sql_errno.set(0);
         self.master_server_id.set(0);
+        self.sql_delay.set(-1);
+        self.sql_remaining_delay.set(-1);
         self.reset_channel_metrics();
     }
 
     fn reset_channel_metrics(&self) {
         self.relay_log_space_by_channel.reset();
         self.relay_log_pos_by_channel.reset();
+        self.read_master_log_pos_by_channel.reset();
+        self.exec_master_log_pos_by_channel.reset();
+        self.apply_lag_bytes_by_channel.reset();
         self.seconds_behind_master_by_channel.reset();
         self.io_running_by_channel.reset();
         self.sql_running_by_channel.reset();
         self.last_io_errno_by_channel.reset();
         self.last_sql_errno_by_channel.reset();
         self.master_server_id_by_channel.reset();
+        self.sql_delay_by_channel.reset();
+        self.sql_remaining_delay_by_channel.reset();
+        // Reset every scrape so a fixed error doesn't leave its message behind
+        self.last_error_info.reset();
+        self.gtid_transactions_behind.reset();
+        // Reset so a CHANGE MASTER TO doesn't leave the old primary behind
+        self.master.reset();
+    }
+
+    /// Per-domain GTID gap. `Gtid_Slave_Pos` is only reported by `SHOW ALL SLAVES STATUS`;
+    /// without it, or without GTID replication, no series are emitted.
+    fn set_gtid_metrics(&self, rows: &[MySqlRow]) {
+        let Some(slave_pos) = rows
+            .iter()
+            .find_map(|row| parse_string_from_columns(row, &["Gtid_Slave_Pos"]))
+        else {
+            return;
+        };
+
+        let io_positions: Vec<String> = rows
+            .iter()
+            .filter_map(|row| parse_string_from_columns(row, &["Gtid_IO_Pos"]))
+            .collect();
+
+        for (domain, behind) in gtid_transactions_behind(&io_positions, &slave_pos) {
+            self.gtid_transactions_behind
+                .with_label_values(&[domain.to_string().as_str()])
+                .set(i64::try_from(behind).unwrap_or(i64::MAX));
+        }
+    }
+
+    fn set_channel_metrics(&self, channel: &ReplicaChannelStatus) {
+        let labels = [
+            channel.channel_name.as_str(),
+            channel.connection_name.as_str(),
+        ];
+        self.relay_log_space_by_channel
+            .with_label_values(&labels)
+            .set(channel.relay_log_space);
+        self.relay_log_pos_by_channel
+            .with_label_values(&labels)
+            .set(channel.relay_log_pos);
+        self.read_master_log_pos_by_channel
+            .with_label_values(&labels)
+            .set(channel.read_master_log_pos);
+        self.exec_master_log_pos_by_channel
+            .with_label_values(&labels)
+            .set(channel.relay_log_pos);
+        self.apply_lag_bytes_by_channel
+            .with_label_values(&labels)
+            .set(channel.apply_lag_bytes.unwrap_or(-1));
+        self.seconds_behind_master_by_channel
+            .with_label_values(&labels)
+            .set(channel.seconds_behind_master.unwrap_or(-1));
+        self.io_running_by_channel
+            .with_label_values(&labels)
+            .set(channel.io_running);
+        self.sql_running_by_channel
+            .with_label_values(&labels)
+            .set(channel.sql_running);
+        self.last_io_errno_by_channel
+            .with_label_values(&labels)
+            .set(channel.last_io_errno);
+        self.last_sql_errno_by_channel
+            .with_label_values(&labels)
+            .set(channel.last_sql_errno);
+        self.master_server_id_by_channel
+            .with_label_values(&labels)
+            .set(channel.master_server_id);
+        self.last_error_info
+            .with_label_values(&[
+                channel.channel_name.as_str(),
+                channel.connection_name.as_str(),
+                channel.last_io_error.as_str(),
+                channel.last_sql_error.as_str(),
+            ])
+            .set(1);
+        if !channel.master_host.is_empty() {
+            self.master
+                .with_label_values(&[
+                    channel.channel_name.as_str(),
+                    channel.connection_name.as_str(),
+                    channel.master_host.as_str(),
+                    channel.master_port.as_str(),
+                ])
+                .set(1);
+        }
+        // Servers that don't report SQL_Delay get no delay series for the channel.
+        if let Some(delay) = channel.sql_delay {
+            self.sql_delay_by_channel
+                .with_label_values(&labels)
+                .set(delay);
+            self.sql_remaining_delay_by_channel
+                .with_label_values(&labels)
+                .set(channel.sql_remaining_delay.unwrap_or(0));
+        }
     }
 
     /// Collect replica status metrics from SHOW SLAVE STATUS.
     ///
+    /// On a writable server whose `replication_connection_configuration` is empty the query
+    /// is skipped and the metrics report "not a replica".
+    ///
     /// # Errors
     ///
     /// Returns an error if the database query fails (though queries are best-effort).
     #[instrument(skip(self, pool), level = "debug", fields(sub_collector = "replica_status"))]
-    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+    pub async fn collect(&self, pool: &MySqlPool, role: ServerRole) -> Result<()> {
         let mut configured = None;
 
-        if is_mariadb_version_at_least(100_600) {
+        // MySQL has had the table since 5.7, so its (lower) version number doesn't matter
+        if is_mysql() || is_mariadb_version_at_least(100_600) {
             let config_span = info_span!(
                 "db.query",
                 db.system = "mysql",
@@ -302,6 +584,14 @@ impl ReplicaStatusCollector {
             }
         }
 
+        // A writable server with no configured channel can't have replica status
+        if role == ServerRole::Primary && configured == Some(false) {
+            debug!("primary without replication channels; skipping SHOW SLAVE STATUS");
+            self.clear_replica_metrics();
+            self.replica_configured.set(0);
+            return Ok(());
+        }
+
         let rows = match query_replica_status_rows(pool).await {
             Ok(rows) => rows,
             Err(e) => {
@@ -322,39 +612,16 @@ impl ReplicaStatusCollector {
 
             let channels: Vec<_> = rows.iter().map(parse_channel_status).collect();
             for channel in &channels {
-                let labels = [
-                    channel.channel_name.as_str(),
-                    channel.connection_name.as_str(),
-                ];
-                self.relay_log_space_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.relay_log_space);
-                self.relay_log_pos_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.relay_log_pos);
-                self.seconds_behind_master_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.seconds_behind_master.unwrap_or(-1));
-                self.io_running_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.io_running);
-                self.sql_running_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.sql_running);
-                self.last_io_errno_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.last_io_errno);
-                self.last_sql_errno_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.last_sql_errno);
-                self.master_server_id_by_channel
-                    .with_label_values(&labels)
-                    .set(channel.master_server_id);
+                self.set_channel_metrics(channel);
             }
+            self.set_gtid_metrics(&rows);
 
             let aggregate = aggregate_channel_statuses(&channels);
             self.relay_log_space.set(aggregate.relay_log_space);
             self.relay_log_pos.set(aggregate.relay_log_pos);
+            self.read_master_log_pos.set(aggregate.read_master_log_pos);
+            self.exec_master_log_pos.set(aggregate.relay_log_pos);
+            self.apply_lag_bytes.set(aggregate.apply_lag_bytes);
             self.seconds_behind_master
                 .set(aggregate.seconds_behind_master);
             self.io_running.set(aggregate.io_running);
@@ -362,6 +629,9 @@ impl ReplicaStatusCollector {
             self.last_io_errno.set(aggregate.last_io_errno);
             self.last_sql_errno.set(aggregate.last_sql_errno);
             self.master_server_id.set(aggregate.master_server_id);
+            self.sql_delay.set(aggregate.sql_delay);
+            self.sql_remaining_delay
+                .set(aggregate.sql_remaining_delay);
         }
 
         self.replica_configured
@@ -385,7 +655,13 @@ async fn query_replica_status_rows(pool: &MySqlPool) -> Result<Vec<MySqlRow>> {
     let mut last_error = None;
     let mut had_empty_success = false;
 
-    for query in REPLICA_STATUS_QUERY_CANDIDATES {
+    let candidates = if is_mysql() {
+        MYSQL_REPLICA_STATUS_QUERIES
+    } else {
+        REPLICA_STATUS_QUERY_CANDIDATES
+    };
+
+    for query in candidates {
         let span = info_span!(
             "db.query",
             db.system = "mysql",
@@ -423,12 +699,26 @@ async fn query_replica_status_rows(pool: &MySqlPool) -> Result<Vec<MySqlRow>> {
 fn parse_channel_status(row: &MySqlRow) -> ReplicaChannelStatus {
     let (channel_name, connection_name) = parse_channel_labels(row);
 
+    let read_file = parse_string_from_columns(row, &["Master_Log_File", "Source_Log_File"]);
+    let read_pos = parse_i64_from_columns(row, &["Read_Master_Log_Pos", "Read_Source_Log_Pos"])
+        .unwrap_or_default();
+    let exec_file =
+        parse_string_from_columns(row, &["Relay_Master_Log_File", "Relay_Source_Log_File"]);
+    let exec_pos = parse_i64_from_columns(row, &["Exec_Master_Log_Pos", "Exec_Source_Log_Pos"])
+        .unwrap_or_default();
+
     ReplicaChannelStatus {
         channel_name,
         connection_name,
         relay_log_space: parse_i64_from_columns(row, &["Relay_Log_Space"]).unwrap_or_default(),
-        relay_log_pos: parse_i64_from_columns(row, &["Exec_Master_Log_Pos", "Exec_Source_Log_Pos"])
-            .unwrap_or_default(),
+        relay_log_pos: exec_pos,
+        read_master_log_pos: read_pos,
+        apply_lag_bytes: apply_lag_bytes(
+            read_file.as_deref(),
+            read_pos,
+            exec_file.as_deref(),
+            exec_pos,
+        ),
         seconds_behind_master: parse_i64_from_columns(
             row,
             &["Seconds_Behind_Master", "Seconds_Behind_Source"],
@@ -444,6 +734,44 @@ fn parse_channel_status(row: &MySqlRow) -> ReplicaChannelStatus {
         last_sql_errno: parse_i64_from_columns(row, &["Last_SQL_Errno"]).unwrap_or_default(),
         master_server_id: parse_i64_from_columns(row, &["Master_Server_Id", "Source_Server_Id"])
             .unwrap_or_default(),
+        master_host: parse_string_from_columns(row, &["Master_Host", "Source_Host"])
+            .unwrap_or_default(),
+        master_port: parse_i64_from_columns(row, &["Master_Port", "Source_Port"])
+            .map(|port| port.to_string())
+            .unwrap_or_default(),
+        sql_delay: parse_i64_from_columns(row, &["SQL_Delay"]),
+        // NULL unless the SQL thread is currently waiting out the delay
+        sql_remaining_delay: parse_i64_from_columns(row, &["SQL_Remaining_Delay"]),
+        last_io_error: error_label(parse_string_from_columns(row, &["Last_IO_Error"]).as_deref()),
+        last_sql_error: error_label(
+            parse_string_from_columns(row, &["Last_SQL_Error"]).as_deref(),
+        ),
+    }
+}
+
+/// Bytes the I/O thread has read from the source binlog that the SQL thread hasn't executed.
+///
+/// Positions are offsets within one binlog file, so the difference only means something while
+/// both threads are on the same file; otherwise (or if either file is unknown) it's `None`.
+fn apply_lag_bytes(
+    read_file: Option<&str>,
+    read_pos: i64,
+    exec_file: Option<&str>,
+    exec_pos: i64,
+) -> Option<i64> {
+    match (read_file, exec_file) {
+        (Some(read), Some(exec)) if !read.is_empty() && read == exec => {
+            Some(read_pos.saturating_sub(exec_pos).max(0))
+        }
+        _ => None,
+    }
+}
+
+/// Turn a `Last_*_Error` message into a label value: `"none"` when empty, truncated otherwise.
+fn error_label(message: Option<&str>) -> String {
+    match message.map(str::trim) {
+        None | Some("") => "none".to_string(),
+        Some(message) => message.chars().take(MAX_ERROR_LABEL_CHARS).collect(),
     }
 }
 
@@ -469,6 +797,8 @@ fn parse_channel_labels(row: &MySqlRow) -> (String, String) {
 fn aggregate_channel_statuses(channels: &[ReplicaChannelStatus]) -> AggregatedReplicaStatus {
     let mut relay_log_space = 0_i64;
     let mut relay_log_pos = 0_i64;
+    let mut read_master_log_pos = 0_i64;
+    let mut apply_lag_bytes: Option<i64> = None;
     let mut seconds_behind_master: Option<i64> = None;
     let mut io_running = true;
     let mut sql_running = true;
@@ -476,10 +806,16 @@ fn aggregate_channel_statuses(channels: &[ReplicaChannelStatus]) -> AggregatedRe
     let mut last_sql_errno = 0_i64;
     let mut master_server_id = None;
     let mut mixed_master_server_id = false;
+    let mut sql_delay: Option<i64> = None;
+    let mut sql_remaining_delay: Option<i64> = None;
 
     for channel in channels {
         relay_log_space = relay_log_space.saturating_add(channel.relay_log_space);
         relay_log_pos = relay_log_pos.max(channel.relay_log_pos);
+        read_master_log_pos = read_master_log_pos.max(channel.read_master_log_pos);
+        if let Some(lag) = channel.apply_lag_bytes {
+            apply_lag_bytes = Some(apply_lag_bytes.map_or(lag, |current| current.max(lag)));
+        }
         if let Some(lag) = channel.seconds_behind_master {
             seconds_behind_master = Some(seconds_behind_master.map_or(lag, |current| current.max(lag)));
         }
@@ -488,6 +824,13 @@ fn aggregate_channel_statuses(channels: &[ReplicaChannelStatus]) -> AggregatedRe
         last_io_errno = last_io_errno.max(channel.last_io_errno);
         last_sql_errno = last_sql_errno.max(channel.last_sql_errno);
 
+        if let Some(delay) = channel.sql_delay {
+            sql_delay = Some(sql_delay.map_or(delay, |current| current.max(delay)));
+            let remaining = channel.sql_remaining_delay.unwrap_or(0);
+            sql_remaining_delay =
+                Some(sql_remaining_delay.map_or(remaining, |current| current.max(remaining)));
+        }
+
         if channel.master_server_id > 0 {
             if let Some(current) = master_server_id {
                 if current != channel.master_server_id {
@@ -502,6 +845,8 @@ fn aggregate_channel_statuses(channels: &[ReplicaChannelStatus]) -> AggregatedRe
     AggregatedReplicaStatus {
         relay_log_space,
         relay_log_pos,
+        read_master_log_pos,
+        apply_lag_bytes: apply_lag_bytes.unwrap_or(-1),
         seconds_behind_master: seconds_behind_master.unwrap_or(-1),
         io_running: i64::from(io_running),
         sql_running: i64::from(sql_running),
@@ -512,10 +857,12 @@ fn aggregate_channel_statuses(channels: &[ReplicaChannelStatus]) -> AggregatedRe
         } else {
             master_server_id.unwrap_or_default()
         },
+        sql_delay: sql_delay.unwrap_or(-1),
+        sql_remaining_delay: sql_remaining_delay.unwrap_or(-1),
     }
 }
 
-fn parse_i64_from_columns(row: &MySqlRow, columns: &[&str]) -> Option<i64> {
+pub(super) fn parse_i64_from_columns(row: &MySqlRow, columns: &[&str]) -> Option<i64> {
     for column in columns {
         let unsigned = row.try_get::<Option<u64>, _>(*column).ok().flatten();
         let signed = row.try_get::<Option<i64>, _>(*column).ok().flatten();
@@ -540,7 +887,7 @@ fn parse_i64_from_values(
         .or_else(|| text.and_then(|value| value.parse::<i64>().ok()))
 }
 
-fn parse_string_from_columns(row: &MySqlRow, columns: &[&str]) -> Option<String> {
+pub(super) fn parse_string_from_columns(row: &MySqlRow, columns: &[&str]) -> Option<String> {
     for column in columns {
         if let Some(value) = row.try_get::<Option<String>, _>(*column).ok().flatten() {
             return Some(value);
@@ -550,6 +897,46 @@ fn parse_string_from_columns(row: &MySqlRow, columns: &[&str]) -> Option<String>
     None
 }
 
+/// Parse a GTID position list (`domain-server_id-seq_no`, comma-separated) into the highest
+/// sequence number per domain. Malformed entries are skipped.
+fn parse_gtid_pos(value: &str) -> BTreeMap<u32, u64> {
+    let mut positions = BTreeMap::new();
+
+    for gtid in value.split(',').map(str::trim).filter(|g| !g.is_empty()) {
+        let mut parts = gtid.splitn(3, '-');
+        let (Some(domain), Some(_server_id), Some(seq_no)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let (Ok(domain), Ok(seq_no)) = (domain.parse::<u32>(), seq_no.parse::<u64>()) else {
+            continue;
+        };
+
+        let entry = positions.entry(domain).or_insert(seq_no);
+        *entry = (*entry).max(seq_no);
+    }
+
+    positions
+}
+
+/// Transactions received but not yet applied, per domain: the received sequence number
+/// (highest across channels) minus the applied one. Sequence numbers within a domain are
+/// consecutive, so the difference is a transaction count.
+fn gtid_transactions_behind(io_positions: &[String], slave_pos: &str) -> BTreeMap<u32, u64> {
+    let applied = parse_gtid_pos(slave_pos);
+    let mut behind = BTreeMap::new();
+
+    for io_pos in io_positions {
+        for (domain, received) in parse_gtid_pos(io_pos) {
+            let gap = received.saturating_sub(applied.get(&domain).copied().unwrap_or(0));
+            let entry = behind.entry(domain).or_insert(gap);
+            *entry = (*entry).max(gap);
+        }
+    }
+
+    behind
+}
+
 fn parse_running(value: Option<&str>) -> i64 {
     match value.map(str::to_ascii_lowercase).as_deref() {
         Some("yes" | "on" | "running") => 1,
@@ -566,9 +953,175 @@ impl Default for ReplicaStatusCollector {
 #[cfg(test)]
 mod tests {
     use super::{
-        REPLICA_STATUS_QUERY_CANDIDATES, ReplicaChannelStatus, aggregate_channel_statuses,
-        parse_i64_from_values, parse_running,
+        MAX_ERROR_LABEL_CHARS, REPLICA_STATUS_QUERY_CANDIDATES, ReplicaChannelStatus,
+        ReplicaStatusCollector, aggregate_channel_statuses, apply_lag_bytes, error_label,
+        gtid_transactions_behind, parse_gtid_pos, parse_i64_from_values, parse_running,
     };
+    use std::collections::BTreeMap;
+    use prometheus::core::Collector as _;
+
+    #[test]
+    fn error_label_uses_none_for_empty_messages() {
+        assert_eq!(error_label(None), "none");
+        assert_eq!(error_label(Some("")), "none");
+        assert_eq!(error_label(Some("  ")), "none");
+        assert_eq!(
+            error_label(Some("Error connecting to master 'repl@db1:3306' - retry-time: 60")),
+            "Error connecting to master 'repl@db1:3306' - retry-time: 60"
+        );
+    }
+
+    #[test]
+    fn last_error_info_is_cleared_on_reset() {
+        let collector = ReplicaStatusCollector::new();
+        let channel = ReplicaChannelStatus {
+            channel_name: "default".to_string(),
+            connection_name: "default".to_string(),
+            relay_log_space: 0,
+            relay_log_pos: 0,
+            read_master_log_pos: 0,
+            apply_lag_bytes: Some(0),
+            seconds_behind_master: None,
+            io_running: 0,
+            sql_running: 1,
+            last_io_errno: 2003,
+            last_sql_errno: 0,
+            master_server_id: 0,
+            master_host: "db1".to_string(),
+            master_port: "3306".to_string(),
+            sql_delay: None,
+            sql_remaining_delay: None,
+            last_io_error: "error connecting to master".to_string(),
+            last_sql_error: "none".to_string(),
+        };
+
+        collector.set_channel_metrics(&channel);
+        assert_eq!(
+            collector
+                .last_error_info
+                .with_label_values(&[
+                    "default",
+                    "default",
+                    "error connecting to master",
+                    "none"
+                ])
+                .get(),
+            1
+        );
+
+        collector.reset_channel_metrics();
+        assert!(
+            collector
+                .last_error_info
+                .collect()
+                .iter()
+                .all(|mf| mf.get_metric().is_empty())
+        );
+    }
+
+    #[test]
+    fn replica_master_follows_channel_primary() {
+        let collector = ReplicaStatusCollector::new();
+        let mut channel = ReplicaChannelStatus {
+            channel_name: "default".to_string(),
+            connection_name: "default".to_string(),
+            relay_log_space: 0,
+            relay_log_pos: 0,
+            read_master_log_pos: 0,
+            apply_lag_bytes: Some(0),
+            seconds_behind_master: Some(0),
+            io_running: 1,
+            sql_running: 1,
+            last_io_errno: 0,
+            last_sql_errno: 0,
+            master_server_id: 1,
+            master_host: "db1.example.com".to_string(),
+            master_port: "3306".to_string(),
+            sql_delay: None,
+            sql_remaining_delay: None,
+            last_io_error: "none".to_string(),
+            last_sql_error: "none".to_string(),
+        };
+
+        collector.set_channel_metrics(&channel);
+        assert_eq!(
+            collector
+                .master
+                .with_label_values(&["default", "default", "db1.example.com", "3306"])
+                .get(),
+            1
+        );
+
+        // After a failover only the new primary is reported
+        channel.master_host = "db2.example.com".to_string();
+        collector.reset_channel_metrics();
+        collector.set_channel_metrics(&channel);
+
+        let hosts: Vec<String> = collector
+            .master
+            .collect()
+            .iter()
+            .flat_map(|mf| mf.get_metric().iter())
+            .flat_map(|m| m.get_label().iter())
+            .filter(|l| l.name() == "master_host")
+            .map(|l| l.value().to_string())
+            .collect();
+        assert_eq!(hosts, vec!["db2.example.com"]);
+    }
+
+    #[test]
+    fn parses_gtid_positions_per_domain() {
+        assert_eq!(
+            parse_gtid_pos("0-1-100, 1-2-50,1-3-40"),
+            BTreeMap::from([(0, 100), (1, 50)])
+        );
+        assert_eq!(parse_gtid_pos(""), BTreeMap::new());
+        assert_eq!(parse_gtid_pos("garbage,0-1"), BTreeMap::new());
+    }
+
+    #[test]
+    fn gtid_transactions_behind_compares_received_and_applied() {
+        let io_positions = vec!["0-1-120".to_string(), "1-2-60".to_string()];
+
+        assert_eq!(
+            gtid_transactions_behind(&io_positions, "0-1-100,1-2-60"),
+            BTreeMap::from([(0, 20), (1, 0)])
+        );
+
+        // Nothing applied yet in domain 1
+        assert_eq!(
+            gtid_transactions_behind(&io_positions, "0-1-120"),
+            BTreeMap::from([(0, 0), (1, 60)])
+        );
+
+        // Applied ahead of received (e.g. after RESET SLAVE) is never negative
+        assert_eq!(
+            gtid_transactions_behind(&["0-1-5".to_string()], "0-1-10"),
+            BTreeMap::from([(0, 0)])
+        );
+    }
+
+    #[test]
+    fn apply_lag_bytes_needs_the_same_binlog_file() {
+        let file = Some("mysql-bin.000042");
+        assert_eq!(apply_lag_bytes(file, 5_000, file, 1_200), Some(3_800));
+        assert_eq!(apply_lag_bytes(file, 1_200, file, 1_200), Some(0));
+
+        // I/O thread already on the next file: the offsets aren't comparable
+        assert_eq!(
+            apply_lag_bytes(Some("mysql-bin.000043"), 300, file, 1_200),
+            None
+        );
+        assert_eq!(apply_lag_bytes(None, 300, None, 100), None);
+        assert_eq!(apply_lag_bytes(Some(""), 300, Some(""), 100), None);
+    }
+
+    #[test]
+    fn error_label_truncates_long_messages() {
+        let long = "é".repeat(MAX_ERROR_LABEL_CHARS + 10);
+        let label = error_label(Some(&long));
+        assert_eq!(label.chars().count(), MAX_ERROR_LABEL_CHARS);
+    }
 
     #[test]
     fn parses_unsigned_master_server_id() {
@@ -625,48 +1178,76 @@ mod tests {
                 connection_name: "a".to_string(),
                 relay_log_space: 20,
                 relay_log_pos: 50,
+                read_master_log_pos: 550,
+                apply_lag_bytes: Some(500),
                 seconds_behind_master: Some(3),
                 io_running: 1,
                 sql_running: 1,
                 last_io_errno: 0,
                 last_sql_errno: 0,
                 master_server_id: 11,
+                master_host: "db1".to_string(),
+                master_port: "3306".to_string(),
+                sql_delay: None,
+                sql_remaining_delay: None,
+                last_io_error: "none".to_string(),
+                last_sql_error: "none".to_string(),
             },
             ReplicaChannelStatus {
                 channel_name: "b".to_string(),
                 connection_name: "b".to_string(),
                 relay_log_space: 30,
                 relay_log_pos: 100,
+                read_master_log_pos: 100,
+                apply_lag_bytes: None,
                 seconds_behind_master: Some(8),
                 io_running: 1,
                 sql_running: 0,
                 last_io_errno: 0,
                 last_sql_errno: 123,
                 master_server_id: 11,
+                master_host: "db1".to_string(),
+                master_port: "3306".to_string(),
+                sql_delay: None,
+                sql_remaining_delay: None,
+                last_io_error: "none".to_string(),
+                last_sql_error: "none".to_string(),
             },
             ReplicaChannelStatus {
                 channel_name: "c".to_string(),
                 connection_name: "c".to_string(),
                 relay_log_space: 5,
                 relay_log_pos: 90,
+                read_master_log_pos: 110,
+                apply_lag_bytes: Some(20),
                 seconds_behind_master: None,
                 io_running: 0,
                 sql_running: 0,
                 last_io_errno: 9,
                 last_sql_errno: 0,
                 master_server_id: 11,
+                master_host: "db1".to_string(),
+                master_port: "3306".to_string(),
+                sql_delay: None,
+                sql_remaining_delay: None,
+                last_io_error: "none".to_string(),
+                last_sql_error: "none".to_string(),
             },
         ];
 
         let aggregate = aggregate_channel_statuses(&channels);
         assert_eq!(aggregate.relay_log_space, 55);
         assert_eq!(aggregate.relay_log_pos, 100);
+        assert_eq!(aggregate.read_master_log_pos, 550);
+        assert_eq!(aggregate.apply_lag_bytes, 500);
         assert_eq!(aggregate.seconds_behind_master, 8);
         assert_eq!(aggregate.io_running, 0);
         assert_eq!(aggregate.sql_running, 0);
         assert_eq!(aggregate.last_io_errno, 9);
         assert_eq!(aggregate.last_sql_errno, 123);
         assert_eq!(aggregate.master_server_id, 11);
+        assert_eq!(aggregate.sql_delay, -1);
+        assert_eq!(aggregate.sql_remaining_delay, -1);
     }
 
     #[test]
@@ -677,28 +1258,83 @@ mod tests {
                 connection_name: "a".to_string(),
                 relay_log_space: 1,
                 relay_log_pos: 1,
+                read_master_log_pos: 1,
+                apply_lag_bytes: Some(0),
                 seconds_behind_master: Some(0),
                 io_running: 1,
                 sql_running: 1,
                 last_io_errno: 0,
                 last_sql_errno: 0,
                 master_server_id: 11,
+                master_host: "db1".to_string(),
+                master_port: "3306".to_string(),
+                sql_delay: None,
+                sql_remaining_delay: None,
+                last_io_error: "none".to_string(),
+                last_sql_error: "none".to_string(),
             },
             ReplicaChannelStatus {
                 channel_name: "b".to_string(),
                 connection_name: "b".to_string(),
                 relay_log_space: 1,
                 relay_log_pos: 1,
+                read_master_log_pos: 1,
+                apply_lag_bytes: Some(0),
                 seconds_behind_master: Some(0),
                 io_running: 1,
                 sql_running: 1,
                 last_io_errno: 0,
                 last_sql_errno: 0,
                 master_server_id: 22,
+                master_host: "db1".to_string(),
+                master_port: "3306".to_string(),
+                sql_delay: None,
+                sql_remaining_delay: None,
+                last_io_error: "none".to_string(),
+                last_sql_error: "none".to_string(),
             },
         ];
 
         let aggregate = aggregate_channel_statuses(&channels);
         assert_eq!(aggregate.master_server_id, 0);
     }
+
+    #[test]
+    fn aggregate_replica_status_reports_max_sql_delay() {
+        let channel = |name: &str, delay: Option<i64>, remaining: Option<i64>| {
+            ReplicaChannelStatus {
+                channel_name: name.to_string(),
+                connection_name: name.to_string(),
+                relay_log_space: 0,
+                relay_log_pos: 0,
+                read_master_log_pos: 0,
+                apply_lag_bytes: Some(0),
+                seconds_behind_master: Some(0),
+                io_running: 1,
+                sql_running: 1,
+                last_io_errno: 0,
+                last_sql_errno: 0,
+                master_server_id: 11,
+                master_host: "db1".to_string(),
+                master_port: "3306".to_string(),
+                sql_delay: delay,
+                sql_remaining_delay: remaining,
+                last_io_error: "none".to_string(),
+                last_sql_error: "none".to_string(),
+            }
+        };
+
+        // Delayed channel waiting on an event, plus an undelayed channel (NULL remaining)
+        let aggregate = aggregate_channel_statuses(&[
+            channel("delayed", Some(3600), Some(1200)),
+            channel("live", Some(0), None),
+        ]);
+        assert_eq!(aggregate.sql_delay, 3600);
+        assert_eq!(aggregate.sql_remaining_delay, 1200);
+
+        // Delayed channel currently caught up: remaining delay NULL means not waiting
+        let aggregate = aggregate_channel_statuses(&[channel("delayed", Some(3600), None)]);
+        assert_eq!(aggregate.sql_delay, 3600);
+        assert_eq!(aggregate.sql_remaining_delay, 0);
+    }
 }
@@ -0,0 +1,65 @@
+use sqlx::MySqlPool;
+use tracing::{debug, info_span};
+use tracing_futures::Instrument as _;
+
+/// Role of the server, detected once per scrape to skip queries that can't apply to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerRole {
+    /// Writable (`@@read_only = 0`): binary logs apply, replica status usually doesn't.
+    Primary,
+    /// Read-only (`@@read_only = 1`): a replica, `SHOW BINARY LOGS` is skipped.
+    Replica,
+    /// `@@read_only` couldn't be read: run every query.
+    Unknown,
+}
+
+impl ServerRole {
+    /// Map `@@read_only` to a role.
+    #[must_use]
+    pub const fn from_read_only(read_only: Option<i64>) -> Self {
+        match read_only {
+            Some(0) => Self::Primary,
+            Some(_) => Self::Replica,
+            None => Self::Unknown,
+        }
+    }
+
+    /// Detect the role from `@@read_only`.
+    pub async fn detect(pool: &MySqlPool) -> Self {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT @@global.read_only",
+            otel.kind = "client"
+        );
+
+        let read_only = match sqlx::query_scalar::<_, i64>("SELECT @@global.read_only")
+            .fetch_one(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(v) => Some(v),
+            Err(e) => {
+                debug!(error = %e, "failed to read @@read_only; running all replication queries");
+                None
+            }
+        };
+
+        let role = Self::from_read_only(read_only);
+        debug!(?role, "detected server role");
+        role
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_read_only() {
+        assert_eq!(ServerRole::from_read_only(Some(0)), ServerRole::Primary);
+        assert_eq!(ServerRole::from_read_only(Some(1)), ServerRole::Replica);
+        assert_eq!(ServerRole::from_read_only(None), ServerRole::Unknown);
+    }
+}
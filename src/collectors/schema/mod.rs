@@ -1,17 +1,19 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use tracing::instrument;
 
+pub mod sizes;
 pub mod tables;
+use sizes::SchemaSizesCollector;
 use tables::TablesCollector;
 
 /// Basic schema/table size metrics (opt-in; limited to avoid high cardinality).
 #[derive(Clone)]
 pub struct SchemaCollector {
     tables: TablesCollector,
+    sizes: SchemaSizesCollector,
 }
 
 impl SchemaCollector {
@@ -20,6 +22,7 @@ impl SchemaCollector {
     pub fn new() -> Self {
         Self {
             tables: TablesCollector::new(),
+            sizes: SchemaSizesCollector::new(),
         }
     }
 }
@@ -41,9 +44,11 @@ impl Collector for SchemaCollector {
         err,
         fields(collector = "schema")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.tables.table_size_bytes().clone()))?;
         registry.register(Box::new(self.tables.table_rows().clone()))?;
+        registry.register(Box::new(self.sizes.schema_size_bytes().clone()))?;
+        registry.register(Box::new(self.sizes.schema_table_count().clone()))?;
         Ok(())
     }
 
@@ -51,6 +56,7 @@ impl Collector for SchemaCollector {
     fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             self.tables.collect(pool).await?;
+            self.sizes.collect(pool).await?;
             Ok(())
         })
     }
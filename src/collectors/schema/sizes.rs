@@ -0,0 +1,133 @@
+use crate::collectors::util::{SYSTEM_SCHEMAS, database_filter_sql, metric_name};
+use anyhow::Result;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Per-schema size rollup across all tables.
+///
+/// One series per database, so unlike the per-table metrics it isn't limited to the
+/// largest tables.
+#[derive(Clone)]
+pub struct SchemaSizesCollector {
+    schema_size_bytes: IntGaugeVec,
+    schema_table_count: IntGaugeVec,
+}
+
+impl SchemaSizesCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new schema sizes collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        let schema_size_bytes = IntGaugeVec::new(
+            Opts::new(
+                metric_name("info_schema_schema_size_bytes"),
+                "Approximate schema size (data+index of all tables) in bytes",
+            ),
+            &["schema"],
+        )
+        .expect("valid mariadb_info_schema_schema_size_bytes metric");
+
+        let schema_table_count = IntGaugeVec::new(
+            Opts::new(
+                metric_name("info_schema_schema_table_count"),
+                "Number of tables in the schema",
+            ),
+            &["schema"],
+        )
+        .expect("valid mariadb_info_schema_schema_table_count metric");
+
+        Self {
+            schema_size_bytes,
+            schema_table_count,
+        }
+    }
+
+    /// Get the schema size metric for registration.
+    #[must_use]
+    pub const fn schema_size_bytes(&self) -> &IntGaugeVec {
+        &self.schema_size_bytes
+    }
+
+    /// Get the schema table count metric for registration.
+    #[must_use]
+    pub const fn schema_table_count(&self) -> &IntGaugeVec {
+        &self.schema_table_count
+    }
+
+    /// Collect size and table count per schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[instrument(
+        skip(self, pool),
+        level = "debug",
+        fields(sub_collector = "schema_sizes")
+    )]
+    pub async fn collect(&self, pool: &MySqlPool) -> Result<()> {
+        // Reset metrics so dropped schemas disappear
+        self.schema_size_bytes.reset();
+        self.schema_table_count.reset();
+
+        let system = SYSTEM_SCHEMAS
+            .iter()
+            .map(|s| format!("'{s}'"))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        // --include-databases / --exclude-databases, bound as parameters
+        let (filter, binds) = database_filter_sql("TABLE_SCHEMA");
+
+        let query = format!(
+            "SELECT TABLE_SCHEMA,
+                    CAST(SUM(COALESCE(DATA_LENGTH,0) + COALESCE(INDEX_LENGTH,0)) AS UNSIGNED) AS size_bytes,
+                    CAST(COUNT(*) AS UNSIGNED) AS table_count
+             FROM information_schema.tables
+             WHERE TABLE_SCHEMA NOT IN ({system}){filter}
+             GROUP BY TABLE_SCHEMA"
+        );
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT per-schema sizes",
+            otel.kind = "client"
+        );
+
+        let rows = binds
+            .into_iter()
+            .fold(
+                sqlx::query_as::<_, (String, u64, u64)>(sqlx::AssertSqlSafe(query)),
+                sqlx::query::QueryAs::bind,
+            )
+            .fetch_all(pool)
+            .instrument(span)
+            .await?;
+
+        debug!("Schema sizes collector found {} schemas", rows.len());
+
+        for (schema, size_bytes, table_count) in rows {
+            self.schema_size_bytes
+                .with_label_values(&[schema.as_str()])
+                .set(i64::try_from(size_bytes).unwrap_or(i64::MAX));
+            self.schema_table_count
+                .with_label_values(&[schema.as_str()])
+                .set(i64::try_from(table_count).unwrap_or(i64::MAX));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for SchemaSizesCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
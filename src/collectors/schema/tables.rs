@@ -1,9 +1,15 @@
+use crate::collectors::util::{
+    database_filter_sql, get_schema_engines, metric_name, series_limit,
+};
 use anyhow::Result;
 use prometheus::{IntGaugeVec, Opts};
 use sqlx::MySqlPool;
 use tracing::{info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// Default number of largest tables, overridden by `--max-series-per-collector`.
+const TOP_TABLES: usize = 20;
+
 /// Table metrics collector for schema information.
 #[derive(Clone)]
 pub struct TablesCollector {
@@ -22,19 +28,19 @@ impl TablesCollector {
     pub fn new() -> Self {
         let table_size_bytes = IntGaugeVec::new(
             Opts::new(
-                "mariadb_info_schema_table_size_bytes",
+                metric_name("info_schema_table_size_bytes"),
                 "Approximate table size (data+index) in bytes",
             ),
-            &["schema", "table"],
+            &["schema", "table", "engine"],
         )
         .expect("valid mariadb_info_schema_table_size_bytes metric");
 
         let table_rows = IntGaugeVec::new(
             Opts::new(
-                "mariadb_info_schema_table_rows",
+                metric_name("info_schema_table_rows"),
                 "Approximate row count per table",
             ),
-            &["schema", "table"],
+            &["schema", "table", "engine"],
         )
         .expect("valid mariadb_info_schema_table_rows metric");
 
@@ -62,7 +68,7 @@ impl TablesCollector {
             .collect::<Vec<_>>()
             .join(",");
 
-        // Limit to avoid runaway cardinality: sample up to 20 largest tables.
+        // Limit to avoid runaway cardinality: sample up to 20 largest tables by default.
         let span = info_span!(
             "db.query",
             db.system = "mysql",
@@ -71,36 +77,49 @@ impl TablesCollector {
             otel.kind = "client"
         );
 
+        // --include-databases / --exclude-databases, bound as parameters
+        let (filter, binds) = database_filter_sql("TABLE_SCHEMA");
+
+        // --schema.engines, also bound as parameters
+        let engines = get_schema_engines();
+        let engine_filter = engine_filter_sql(engines.len());
+
         let query = format!(
-            "SELECT TABLE_SCHEMA, TABLE_NAME,
+            "SELECT TABLE_SCHEMA, TABLE_NAME, COALESCE(ENGINE, ''),
                     CAST(COALESCE(DATA_LENGTH,0) + COALESCE(INDEX_LENGTH,0) AS UNSIGNED) AS size_bytes,
                     CAST(COALESCE(TABLE_ROWS,0) AS UNSIGNED) as rows_est
              FROM information_schema.tables
-             WHERE TABLE_SCHEMA NOT IN ({excluded})
+             WHERE TABLE_SCHEMA NOT IN ({excluded}){filter}{engine_filter}
              ORDER BY size_bytes DESC
-             LIMIT 20"
+             LIMIT ?"
         );
 
-        let rows = sqlx::query_as::<_, (String, String, u64, u64)>(sqlx::AssertSqlSafe(query))
+        let rows = binds
+            .into_iter()
+            .chain(engines.iter().map(String::as_str))
+            .fold(
+                sqlx::query_as::<_, (String, String, String, u64, u64)>(sqlx::AssertSqlSafe(
+                    query,
+                )),
+                sqlx::query::QueryAs::bind,
+            )
+            .bind(u64::try_from(series_limit(TOP_TABLES)).unwrap_or(u64::MAX))
             .fetch_all(pool)
             .instrument(span)
             .await?;
 
         tracing::debug!("Schema collector found {} tables", rows.len());
 
-        for (schema, table, size_bytes, rows_est) in rows {
+        for (schema, table, engine, size_bytes, rows_est) in rows {
             tracing::debug!("Setting metrics for {}.{}: size={}, rows={}", schema, table, size_bytes, rows_est);
             #[allow(clippy::cast_possible_wrap)]
             let size_i64 = size_bytes as i64;
             #[allow(clippy::cast_possible_wrap)]
             let rows_i64 = rows_est as i64;
             
-            self.table_size_bytes
-                .with_label_values(&[schema.as_str(), table.as_str()])
-                .set(size_i64);
-            self.table_rows
-                .with_label_values(&[schema.as_str(), table.as_str()])
-                .set(rows_i64);
+            let labels = [schema.as_str(), table.as_str(), engine.as_str()];
+            self.table_size_bytes.with_label_values(&labels).set(size_i64);
+            self.table_rows.with_label_values(&labels).set(rows_i64);
         }
 
         Ok(())
@@ -119,8 +138,28 @@ impl TablesCollector {
     }
 }
 
+/// ` AND LOWER(ENGINE) IN (?, ...)` for `--schema.engines`, empty without a filter.
+fn engine_filter_sql(count: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+
+    format!(" AND LOWER(ENGINE) IN ({})", vec!["?"; count].join(", "))
+}
+
 impl Default for TablesCollector {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_filter_sql() {
+        assert_eq!(engine_filter_sql(0), "");
+        assert_eq!(engine_filter_sql(2), " AND LOWER(ENGINE) IN (?, ?)");
+    }
+}
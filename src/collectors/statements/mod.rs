@@ -1,11 +1,23 @@
-use crate::collectors::{util::PICO_TO_SECONDS, Collector};
+use crate::collectors::{
+    util::{
+        PICO_TO_SECONDS, get_statements_schemas, is_access_denied, is_mariadb_version_below,
+        metric_name, series_limit,
+    },
+    Collector, Registrar,
+};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::{Gauge, GaugeVec, IntGauge, Opts, Registry};
+use prometheus::{Gauge, GaugeVec, IntGauge, Opts};
 use sqlx::MySqlPool;
 use tracing::{info_span, instrument};
 use tracing_futures::Instrument as _;
 
+/// `events_statements_summary_by_digest` is available from `MariaDB` 10.0.
+const MIN_VERSION: i32 = 100_000;
+
+/// Default number of top digests, overridden by `--max-series-per-collector`.
+const TOP_DIGESTS: usize = 5;
+
 /// Statements summary from `performance_schema` (opt-in, lightweight aggregate).
 #[derive(Clone)]
 pub struct StatementsCollector {
@@ -33,7 +45,7 @@ impl StatementsCollector {
 
         let top_digest_latencies = GaugeVec::new(
             Opts::new(
-                "mariadb_perf_schema_digest_latency_seconds",
+                metric_name("perf_schema_digest_latency_seconds"),
                 "Top statement digests by total latency (seconds)",
             ),
             &["digest", "schema"],
@@ -42,27 +54,27 @@ impl StatementsCollector {
 
         Self {
             digest_total: g(
-                "mariadb_perf_schema_digest_total",
+                &metric_name("perf_schema_digest_total"),
                 "Total statements counted in performance_schema digests",
             ),
             digest_errors: g(
-                "mariadb_perf_schema_digest_errors_total",
+                &metric_name("perf_schema_digest_errors_total"),
                 "Total errors across statement digests",
             ),
             digest_warnings: g(
-                "mariadb_perf_schema_digest_warnings_total",
+                &metric_name("perf_schema_digest_warnings_total"),
                 "Total warnings across statement digests",
             ),
             digest_rows_examined: g(
-                "mariadb_perf_schema_digest_rows_examined_total",
+                &metric_name("perf_schema_digest_rows_examined_total"),
                 "Total rows examined across statement digests",
             ),
             digest_rows_sent: g(
-                "mariadb_perf_schema_digest_rows_sent_total",
+                &metric_name("perf_schema_digest_rows_sent_total"),
                 "Total rows sent across statement digests",
             ),
             digest_latency_seconds: Gauge::new(
-                "mariadb_perf_schema_digest_latency_seconds_total",
+                metric_name("perf_schema_digest_latency_seconds_total"),
                 "Total latency across statement digests in picoseconds converted to seconds",
             )
             .expect("valid mariadb_perf_schema_digest_latency_seconds_total metric"),
@@ -88,7 +100,7 @@ impl Collector for StatementsCollector {
         err,
         fields(collector = "statements")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.digest_total.clone()))?;
         registry.register(Box::new(self.digest_errors.clone()))?;
         registry.register(Box::new(self.digest_warnings.clone()))?;
@@ -105,6 +117,11 @@ impl Collector for StatementsCollector {
             // Reset top digests to avoid stale data
             self.top_digest_latencies.reset();
 
+            if is_mariadb_version_below(MIN_VERSION) {
+                tracing::debug!("statement digests require MariaDB 10.0+; skipping collection");
+                return Ok(());
+            }
+
             // Confirm table exists (Performance Schema might be off)
             let exists_span = info_span!(
                 "db.query",
@@ -164,7 +181,7 @@ impl Collector for StatementsCollector {
                 self.digest_latency_seconds.set(latency_seconds);
             }
 
-            // Top digests by latency (limit 5 to keep cardinality sane)
+            // Top digests by latency (limit 5 by default to keep cardinality sane)
             let top_span = info_span!(
                 "db.query",
                 db.system = "mysql",
@@ -173,17 +190,33 @@ impl Collector for StatementsCollector {
                 otel.kind = "client"
             );
 
-            let rows = match sqlx::query_as::<_, (Option<String>, Option<String>, u64)>(
+            // --statements.schemas keeps only the listed schemas, which also drops
+            // background statements without a schema (NULL SCHEMA_NAME)
+            let schemas = get_statements_schemas();
+            let query = format!(
                 "SELECT DIGEST_TEXT, SCHEMA_NAME, CAST(SUM_TIMER_WAIT AS UNSIGNED)
-                 FROM performance_schema.events_statements_summary_by_digest
+                 FROM performance_schema.events_statements_summary_by_digest{}
                  ORDER BY SUM_TIMER_WAIT DESC
-                 LIMIT 5",
-            )
+                 LIMIT ?",
+                schema_filter_sql(schemas.len())
+            );
+
+            let rows = match schemas
+                .iter()
+                .map(String::as_str)
+                .fold(
+                    sqlx::query_as::<_, (Option<String>, Option<String>, u64)>(
+                        sqlx::AssertSqlSafe(query),
+                    ),
+                    sqlx::query::QueryAs::bind,
+                )
+                .bind(u64::try_from(series_limit(TOP_DIGESTS)).unwrap_or(u64::MAX))
             .fetch_all(pool)
             .instrument(top_span)
             .await
             {
                 Ok(r) => r,
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
                 Err(e) => {
                     tracing::error!("Statements digest query failed: {}", e);
                     vec![]
@@ -208,3 +241,24 @@ impl Collector for StatementsCollector {
         false
     }
 }
+
+/// `WHERE SCHEMA_NAME IN (?, ..)` with one placeholder per schema, or nothing for no filter.
+fn schema_filter_sql(count: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+
+    format!(" WHERE SCHEMA_NAME IN ({})", vec!["?"; count].join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_filter_sql() {
+        assert_eq!(schema_filter_sql(0), "");
+        assert_eq!(schema_filter_sql(1), " WHERE SCHEMA_NAME IN (?)");
+        assert_eq!(schema_filter_sql(3), " WHERE SCHEMA_NAME IN (?, ?, ?)");
+    }
+}
@@ -0,0 +1,154 @@
+use crate::collectors::{Collector, Registrar, fixture::StatusSnapshot, util::metric_name};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{GaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+const STATUS_QUERY: &str =
+    "SELECT VARIABLE_NAME, COALESCE(VARIABLE_VALUE, '') FROM information_schema.global_status";
+
+/// Every numeric global status variable, unfiltered (opt-in).
+///
+/// Exports one `mariadb_global_status_raw{variable}` series per variable (several hundred on a
+/// typical server, more with plugins loaded), for status variables the curated default collector
+/// doesn't cover. Non-numeric values (`ON`, file names, ...) are skipped.
+#[derive(Clone)]
+pub struct StatusAllCollector {
+    raw: GaugeVec,
+}
+
+impl StatusAllCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new raw status collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            raw: GaugeVec::new(
+                Opts::new(
+                    metric_name("global_status_raw"),
+                    "Value of every numeric global status variable (SHOW GLOBAL STATUS), unfiltered",
+                ),
+                &["variable"],
+            )
+            .expect("valid mariadb_global_status_raw metric"),
+        }
+    }
+
+    fn set_status(&self, rows: &[(String, String)]) {
+        for (name, value) in rows {
+            if let Some(value) = parse_numeric(value) {
+                self.raw
+                    .with_label_values(&[name.to_ascii_lowercase().as_str()])
+                    .set(value);
+            }
+        }
+    }
+}
+
+/// Parse a status value as a number; empty and non-numeric values are `None`.
+fn parse_numeric(value: &str) -> Option<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+impl Default for StatusAllCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for StatusAllCollector {
+    fn name(&self) -> &'static str {
+        "status_all"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "status_all")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.raw.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "status_all", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // Reset so variables of an unloaded plugin disappear
+            self.raw.reset();
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = STATUS_QUERY,
+                otel.kind = "client"
+            );
+
+            match sqlx::query_as::<_, (String, String)>(STATUS_QUERY)
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => self.set_status(&rows),
+                Err(e) => debug!(error = %e, "failed to query global status"),
+            }
+
+            Ok(())
+        })
+    }
+
+    fn collect_from_fixture(&self, snapshot: &StatusSnapshot) -> Result<()> {
+        self.raw.reset();
+        self.set_status(&snapshot.global_status);
+        Ok(())
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::core::Collector as _;
+
+    #[test]
+    fn test_set_status_skips_non_numeric() {
+        let collector = StatusAllCollector::new();
+        collector.set_status(&[
+            ("Uptime".to_string(), "3600".to_string()),
+            (
+                "Innodb_buffer_pool_load_status".to_string(),
+                "Buffer pool(s) load completed".to_string(),
+            ),
+            ("Ssl_cipher".to_string(), String::new()),
+            ("Rpl_semi_sync_master_status".to_string(), "OFF".to_string()),
+            ("Busy_time".to_string(), "0.250000".to_string()),
+        ]);
+
+        let series: usize = collector
+            .raw
+            .collect()
+            .iter()
+            .map(|mf| mf.get_metric().len())
+            .sum();
+        assert_eq!(series, 2);
+        assert!((collector.raw.with_label_values(&["uptime"]).get() - 3600.0).abs() < f64::EPSILON);
+        assert!(
+            (collector.raw.with_label_values(&["busy_time"]).get() - 0.25).abs() < f64::EPSILON
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{SYSTEM_SCHEMAS, database_filter_sql, is_access_denied, metric_name, series_limit},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGauge, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Unused indexes exported per scrape, to bound cardinality
+/// (overridden by `--max-series-per-collector`).
+const TOP_INDEXES: usize = 25;
+
+/// Optimization hints from the `sys` schema views (opt-in; requires `SELECT` on `sys` and
+/// `performance_schema`).
+///
+/// `sys.schema_unused_indexes` lists the indexes `performance_schema` saw no reads on since
+/// startup; `sys.statements_with_full_table_scans` lists the statement digests that ran
+/// without an index. Both are only as good as the instrumentation behind them: right after a
+/// restart every index looks unused. Skipped when the `sys` schema isn't installed.
+#[derive(Clone)]
+pub struct SysCollector {
+    unused_indexes: IntGaugeVec,
+    full_scan_statements: IntGauge,
+}
+
+impl SysCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new `sys` schema collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            unused_indexes: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("sys_unused_indexes"),
+                    "Index without reads since startup (sys.schema_unused_indexes, always 1)",
+                ),
+                &["schema", "table", "index"],
+            )
+            .expect("valid mariadb_sys_unused_indexes metric"),
+            full_scan_statements: IntGauge::new(
+                metric_name("sys_full_scan_statements_total"),
+                "Statement executions that used no index (sum of sys.statements_with_full_table_scans.no_index_used_count)",
+            )
+            .expect("valid mariadb_sys_full_scan_statements_total metric"),
+        }
+    }
+
+    fn set_unused_index(&self, schema: &str, table: &str, index: &str) {
+        self.unused_indexes
+            .with_label_values(&[schema, table, index])
+            .set(1);
+    }
+
+    async fn collect_unused_indexes(&self, pool: &MySqlPool) -> Result<()> {
+        let system = SYSTEM_SCHEMAS
+            .iter()
+            .map(|s| format!("'{s}'"))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        // --include-databases / --exclude-databases, bound as parameters
+        let (filter, binds) = database_filter_sql("object_schema");
+
+        let query = format!(
+            "SELECT object_schema, object_name, index_name
+             FROM sys.schema_unused_indexes
+             WHERE object_schema NOT IN ({system}){filter}
+             ORDER BY object_schema, object_name, index_name
+             LIMIT ?"
+        );
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT FROM sys.schema_unused_indexes",
+            otel.kind = "client"
+        );
+
+        let rows = match binds
+            .into_iter()
+            .fold(
+                sqlx::query_as::<_, (String, String, String)>(sqlx::AssertSqlSafe(query)),
+                sqlx::query::QueryAs::bind,
+            )
+            .bind(u64::try_from(series_limit(TOP_INDEXES)).unwrap_or(u64::MAX))
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(rows) => rows,
+            Err(e) if is_access_denied(&e) => return Err(e.into()),
+            Err(e) => {
+                debug!(error = %e, "sys.schema_unused_indexes query failed; skipping");
+                return Ok(());
+            }
+        };
+
+        for (schema, table, index) in rows {
+            self.set_unused_index(&schema, &table, &index);
+        }
+
+        Ok(())
+    }
+
+    async fn collect_full_scan_statements(&self, pool: &MySqlPool) -> Result<()> {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT FROM sys.statements_with_full_table_scans",
+            otel.kind = "client"
+        );
+
+        match sqlx::query_scalar::<_, i64>(
+            "SELECT CAST(COALESCE(SUM(no_index_used_count), 0) AS SIGNED)
+             FROM sys.statements_with_full_table_scans",
+        )
+        .fetch_one(pool)
+        .instrument(span)
+        .await
+        {
+            Ok(count) => self.full_scan_statements.set(count),
+            Err(e) if is_access_denied(&e) => return Err(e.into()),
+            Err(e) => debug!(error = %e, "full table scans query failed; skipping"),
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for SysCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for SysCollector {
+    fn name(&self) -> &'static str {
+        "sys"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "sys")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.unused_indexes.clone()))?;
+        registry.register(Box::new(self.full_scan_statements.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "sys", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // Indexes drop out of the list once they are read
+            self.unused_indexes.reset();
+
+            let exists_span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "check sys schema",
+                otel.kind = "client"
+            );
+
+            let has_sys = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM information_schema.schemata WHERE schema_name = 'sys'",
+            )
+            .fetch_one(pool)
+            .instrument(exists_span)
+            .await
+            .unwrap_or(0)
+                > 0;
+
+            if !has_sys {
+                debug!("sys schema not installed; skipping");
+                return Ok(());
+            }
+
+            self.collect_unused_indexes(pool).await?;
+            self.collect_full_scan_statements(pool).await?;
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_unused_index() {
+        let collector = SysCollector::new();
+        collector.set_unused_index("shop", "orders", "idx_created_at");
+
+        assert_eq!(
+            collector
+                .unused_indexes
+                .with_label_values(&["shop", "orders", "idx_created_at"])
+                .get(),
+            1
+        );
+        assert_eq!(collector.full_scan_statements.get(), 0);
+    }
+}
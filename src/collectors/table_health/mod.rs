@@ -0,0 +1,232 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{SYSTEM_SCHEMAS, database_filter_sql, is_access_denied, metric_name, series_limit},
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGauge, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument, warn};
+use tracing_futures::Instrument as _;
+
+/// Tables exported per scrape, least recently checked first, to bound cardinality
+/// (overridden by `--max-series-per-collector`).
+const TOP_TABLES: usize = 25;
+
+/// `DICT_CORRUPT` bit of `INNODB_SYS_INDEXES.TYPE`, set when `InnoDB` found the index corrupt.
+const DICT_CORRUPT: i64 = 16;
+
+/// Post-crash repair indicators (opt-in, requires `PROCESS`).
+///
+/// Exports when each table was last checked (`information_schema.tables.CHECK_TIME`, set by
+/// `CHECK TABLE` on `MyISAM` and Aria; `InnoDB` leaves it `NULL`) and how many `InnoDB` indexes
+/// are flagged corrupt in `INNODB_SYS_INDEXES`. Reading `CHECK_TIME` opens every table, so
+/// this collector can be slow on servers with many tables.
+#[derive(Clone)]
+pub struct TableHealthCollector {
+    check_time: IntGaugeVec,
+    corrupted_indexes: IntGauge,
+}
+
+impl TableHealthCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new table health collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            check_time: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("info_schema_table_check_time_seconds"),
+                    "Unix time of the last CHECK TABLE (information_schema.tables.CHECK_TIME)",
+                ),
+                &["schema", "table"],
+            )
+            .expect("valid mariadb_info_schema_table_check_time_seconds metric"),
+            corrupted_indexes: IntGauge::new(
+                metric_name("innodb_corrupted_indexes"),
+                "Number of InnoDB indexes flagged corrupt (INNODB_SYS_INDEXES.TYPE & 16)",
+            )
+            .expect("valid mariadb_innodb_corrupted_indexes metric"),
+        }
+    }
+
+    fn set_check_time(&self, schema: &str, table: &str, check_time: i64) {
+        self.check_time
+            .with_label_values(&[schema, table])
+            .set(check_time);
+    }
+
+    async fn collect_check_times(&self, pool: &MySqlPool) -> Result<()> {
+        let system = SYSTEM_SCHEMAS
+            .iter()
+            .map(|s| format!("'{s}'"))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        // --include-databases / --exclude-databases, bound as parameters
+        let (filter, binds) = database_filter_sql("TABLE_SCHEMA");
+
+        let query = format!(
+            "SELECT TABLE_SCHEMA, TABLE_NAME, CAST(UNIX_TIMESTAMP(CHECK_TIME) AS SIGNED)
+             FROM information_schema.tables
+             WHERE CHECK_TIME IS NOT NULL AND TABLE_SCHEMA NOT IN ({system}){filter}
+             ORDER BY CHECK_TIME
+             LIMIT ?"
+        );
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "least recently checked tables",
+            otel.kind = "client"
+        );
+
+        let rows = match binds
+            .into_iter()
+            .fold(
+                sqlx::query_as::<_, (String, String, i64)>(sqlx::AssertSqlSafe(query)),
+                sqlx::query::QueryAs::bind,
+            )
+            .bind(u64::try_from(series_limit(TOP_TABLES)).unwrap_or(u64::MAX))
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(rows) => rows,
+            Err(e) if is_access_denied(&e) => return Err(e.into()),
+            Err(e) => {
+                debug!(error = %e, "CHECK_TIME query failed; skipping");
+                return Ok(());
+            }
+        };
+
+        for (schema, table, check_time) in rows {
+            self.set_check_time(&schema, &table, check_time);
+        }
+
+        Ok(())
+    }
+
+    async fn collect_corrupted_indexes(&self, pool: &MySqlPool) -> Result<()> {
+        // Not every server version ships the table
+        let exists_span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "check INNODB_SYS_INDEXES table",
+            otel.kind = "client"
+        );
+
+        let has_table = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema='information_schema' AND table_name='INNODB_SYS_INDEXES'",
+        )
+        .fetch_one(pool)
+        .instrument(exists_span)
+        .await
+        .unwrap_or(0)
+            > 0;
+
+        if !has_table {
+            debug!("INNODB_SYS_INDEXES not available; skipping corrupted indexes");
+            return Ok(());
+        }
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "count corrupt InnoDB indexes",
+            otel.kind = "client"
+        );
+
+        match sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM information_schema.INNODB_SYS_INDEXES WHERE TYPE & ? <> 0",
+        )
+        .bind(DICT_CORRUPT)
+        .fetch_one(pool)
+        .instrument(span)
+        .await
+        {
+            Ok(count) => {
+                if count > 0 {
+                    warn!(count, "InnoDB reports corrupt indexes; run CHECK TABLE");
+                }
+                self.corrupted_indexes.set(count);
+            }
+            Err(e) if is_access_denied(&e) => return Err(e.into()),
+            Err(e) => debug!(error = %e, "INNODB_SYS_INDEXES query failed; skipping"),
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for TableHealthCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for TableHealthCollector {
+    fn name(&self) -> &'static str {
+        "table_health"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "table_health")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.check_time.clone()))?;
+        registry.register(Box::new(self.corrupted_indexes.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "table_health", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // The least recently checked tables change between scrapes
+            self.check_time.reset();
+
+            self.collect_check_times(pool).await?;
+            self.collect_corrupted_indexes(pool).await?;
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    fn required_privileges(&self) -> &[&str] {
+        &["PROCESS"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_check_time() {
+        let collector = TableHealthCollector::new();
+        collector.set_check_time("shop", "orders", 1_741_943_564);
+
+        assert_eq!(
+            collector
+                .check_time
+                .with_label_values(&["shop", "orders"])
+                .get(),
+            1_741_943_564
+        );
+        assert_eq!(collector.corrupted_indexes.get(), 0);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::collectors::{
+    Collector, Registrar,
+    util::{
+        PICO_TO_SECONDS, SYSTEM_SCHEMAS, database_filter_sql, is_access_denied,
+        is_database_included, metric_name, series_limit,
+    },
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{GaugeVec, IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+/// Tables exported per scrape, by total wait time, to bound cardinality
+/// (overridden by `--max-series-per-collector`).
+const TOP_TABLES: usize = 25;
+
+/// Row operations reported by `table_io_waits_summary_by_table`, in query column order.
+const OPERATIONS: [&str; 4] = ["fetch", "insert", "update", "delete"];
+
+/// One row of `table_io_waits_summary_by_table`: counts and timers (picoseconds) per operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TableIoRow {
+    schema: String,
+    table: String,
+    counts: [u64; 4],
+    timers_ps: [u64; 4],
+}
+
+/// Per-table I/O waits from `performance_schema.table_io_waits_summary_by_table` (opt-in).
+///
+/// The per-table companion to the aggregate `Handler_*` status counters, limited to the
+/// tables with the most wait time outside the system schemas and `--exclude-databases`
+/// (and inside `--include-databases` when set).
+#[derive(Clone)]
+pub struct TableIoCollector {
+    waits_seconds: GaugeVec,
+    waits: IntGaugeVec,
+}
+
+impl TableIoCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new table I/O collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            waits_seconds: GaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_table_io_waits_seconds_total"),
+                    "Time spent waiting on table I/O per operation, picoseconds converted to seconds",
+                ),
+                &["schema", "table", "operation"],
+            )
+            .expect("valid mariadb_perf_schema_table_io_waits_seconds_total metric"),
+            waits: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("perf_schema_table_io_waits_total"),
+                    "Number of table I/O wait events per operation",
+                ),
+                &["schema", "table", "operation"],
+            )
+            .expect("valid mariadb_perf_schema_table_io_waits_total metric"),
+        }
+    }
+
+    fn set_row(&self, row: &TableIoRow) {
+        for ((operation, count), timer_ps) in OPERATIONS.iter().zip(row.counts).zip(row.timers_ps) {
+            let labels = [row.schema.as_str(), row.table.as_str(), operation];
+
+            self.waits
+                .with_label_values(&labels)
+                .set(i64::try_from(count).unwrap_or(i64::MAX));
+
+            #[allow(clippy::cast_precision_loss)]
+            self.waits_seconds
+                .with_label_values(&labels)
+                .set(timer_ps as f64 / PICO_TO_SECONDS);
+        }
+    }
+}
+
+impl Default for TableIoCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for TableIoCollector {
+    fn name(&self) -> &'static str {
+        "table_io"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "table_io")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.waits_seconds.clone()))?;
+        registry.register(Box::new(self.waits.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "table_io", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // The top tables change between scrapes; drop the ones that fell out
+            self.waits_seconds.reset();
+            self.waits.reset();
+
+            // Confirm table exists (Performance Schema might be off)
+            let exists_span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "check table_io_waits_summary_by_table table",
+                otel.kind = "client"
+            );
+
+            let has_table = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema='performance_schema' AND table_name='table_io_waits_summary_by_table'",
+            )
+            .fetch_one(pool)
+            .instrument(exists_span)
+            .await
+            .unwrap_or(0)
+                > 0;
+
+            if !has_table {
+                debug!("table_io_waits_summary_by_table not available; skipping collection");
+                return Ok(());
+            }
+
+            // Build exclusion list from constant
+            let system = SYSTEM_SCHEMAS
+                .iter()
+                .map(|s| format!("'{s}'"))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            // --include-databases / --exclude-databases, bound as parameters
+            let (filter, binds) = database_filter_sql("OBJECT_SCHEMA");
+            let limit = series_limit(TOP_TABLES);
+
+            let query = format!(
+                "SELECT OBJECT_SCHEMA, OBJECT_NAME,
+                        CAST(COUNT_FETCH AS UNSIGNED), CAST(COUNT_INSERT AS UNSIGNED),
+                        CAST(COUNT_UPDATE AS UNSIGNED), CAST(COUNT_DELETE AS UNSIGNED),
+                        CAST(SUM_TIMER_FETCH AS UNSIGNED), CAST(SUM_TIMER_INSERT AS UNSIGNED),
+                        CAST(SUM_TIMER_UPDATE AS UNSIGNED), CAST(SUM_TIMER_DELETE AS UNSIGNED)
+                 FROM performance_schema.table_io_waits_summary_by_table
+                 WHERE OBJECT_SCHEMA NOT IN ({system}) AND COUNT_STAR > 0{filter}
+                 ORDER BY SUM_TIMER_WAIT DESC
+                 LIMIT ?"
+            );
+
+            let span = info_span!(
+                "db.query",
+                db.system = "mysql",
+                db.operation = "SELECT",
+                db.statement = "top table I/O waits",
+                otel.kind = "client"
+            );
+
+            let rows = match binds
+                .into_iter()
+                .fold(
+                    sqlx::query_as::<_, (String, String, u64, u64, u64, u64, u64, u64, u64, u64)>(
+                        sqlx::AssertSqlSafe(query),
+                    ),
+                    sqlx::query::QueryAs::bind,
+                )
+                .bind(u64::try_from(limit).unwrap_or(u64::MAX))
+                .fetch_all(pool)
+                .instrument(span)
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) if is_access_denied(&e) => return Err(e.into()),
+                Err(e) => {
+                    debug!(error = %e, "table_io_waits_summary_by_table query failed; skipping");
+                    return Ok(());
+                }
+            };
+
+            let rows = rows
+                .into_iter()
+                .map(
+                    |(schema, table, cf, ci, cu, cd, tf, ti, tu, td)| TableIoRow {
+                        schema,
+                        table,
+                        counts: [cf, ci, cu, cd],
+                        timers_ps: [tf, ti, tu, td],
+                    },
+                );
+
+            for row in top_tables(rows, limit) {
+                self.set_row(&row);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
+
+/// Keep tables allowed by `--include-databases`/`--exclude-databases` and the first
+/// `limit` of them (rows come sorted).
+fn top_tables(
+    rows: impl Iterator<Item = TableIoRow>,
+    limit: usize,
+) -> impl Iterator<Item = TableIoRow> {
+    rows.filter(|row| is_database_included(&row.schema))
+        .take(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(schema: &str, table: &str) -> TableIoRow {
+        TableIoRow {
+            schema: schema.to_string(),
+            table: table.to_string(),
+            counts: [10, 2, 3, 1],
+            timers_ps: [1_500_000_000_000, 0, 250_000_000_000, 0],
+        }
+    }
+
+    #[test]
+    fn test_set_row_per_operation() {
+        let collector = TableIoCollector::new();
+        collector.set_row(&row("shop", "orders"));
+
+        let count = |op: &str| {
+            collector
+                .waits
+                .with_label_values(&["shop", "orders", op])
+                .get()
+        };
+        let seconds = |op: &str| {
+            collector
+                .waits_seconds
+                .with_label_values(&["shop", "orders", op])
+                .get()
+        };
+
+        assert_eq!(count("fetch"), 10);
+        assert_eq!(count("insert"), 2);
+        assert_eq!(count("update"), 3);
+        assert_eq!(count("delete"), 1);
+        assert!((seconds("fetch") - 1.5).abs() < f64::EPSILON);
+        assert!((seconds("update") - 0.25).abs() < f64::EPSILON);
+        assert!(seconds("delete").abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_top_tables_limit() {
+        let rows = (0..TOP_TABLES * 2).map(|i| row("shop", &format!("t{i}")));
+        let top: Vec<TableIoRow> = top_tables(rows, TOP_TABLES).collect();
+
+        assert_eq!(top.len(), TOP_TABLES);
+        assert_eq!(top.first().map(|r| r.table.as_str()), Some("t0"));
+    }
+}
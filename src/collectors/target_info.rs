@@ -0,0 +1,82 @@
+//! `mariadb_exporter_target_info`, carrying the `--external-label` pairs, registered once by
+//! every `CollectorRegistry` when any are set.
+
+use crate::collectors::{Registrar, util::metric_name};
+use anyhow::Result;
+use prometheus::{IntGaugeVec, Opts};
+use std::collections::BTreeMap;
+
+/// Info metric (always 1) with one label per `--external-label key=value`, for joining the
+/// exporter's series with other exporters' on `env`, `region` and the like.
+#[derive(Clone)]
+pub struct TargetInfoCollector {
+    target_info: IntGaugeVec,
+}
+
+impl TargetInfoCollector {
+    /// Create the target info gauge, already set to 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a label name is invalid.
+    pub fn new(labels: &BTreeMap<String, String>) -> Result<Self> {
+        let names: Vec<&str> = labels.keys().map(String::as_str).collect();
+        let values: Vec<&str> = labels.values().map(String::as_str).collect();
+
+        let target_info = IntGaugeVec::new(
+            Opts::new(
+                metric_name("exporter_target_info"),
+                "Labels from --external-label describing this target, always 1",
+            ),
+            &names,
+        )?;
+        target_info.get_metric_with_label_values(&values)?.set(1);
+
+        Ok(Self { target_info })
+    }
+
+    /// Register the target info gauge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry already has a `mariadb_exporter_target_info` metric.
+    pub fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.target_info.clone()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use prometheus::Registry;
+
+    #[test]
+    fn test_target_info_labels() {
+        let labels = BTreeMap::from([
+            ("region".to_string(), "us-east-1".to_string()),
+            ("env".to_string(), "prod".to_string()),
+        ]);
+        let registry = Registry::new();
+        TargetInfoCollector::new(&labels)
+            .unwrap()
+            .register_metrics(&registry)
+            .unwrap();
+
+        let families = registry.gather();
+        let metric = families
+            .iter()
+            .find(|f| f.name() == "mariadb_exporter_target_info")
+            .and_then(|f| f.get_metric().first())
+            .unwrap();
+
+        let pairs: Vec<(&str, &str)> = metric
+            .get_label()
+            .iter()
+            .map(|l| (l.name(), l.value()))
+            .collect();
+        assert_eq!(pairs, vec![("env", "prod"), ("region", "us-east-1")]);
+        assert!((metric.get_gauge().value() - 1.0).abs() < f64::EPSILON);
+    }
+}
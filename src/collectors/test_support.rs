@@ -0,0 +1,79 @@
+//! In-memory collector for unit-testing `CollectorRegistry` without a database.
+
+use crate::collectors::{Collector, Registrar};
+use anyhow::{Result, anyhow};
+use futures::future::BoxFuture;
+use prometheus::IntGauge;
+use sqlx::MySqlPool;
+use std::time::Duration;
+
+/// Registers a single gauge named after the collector and sets it to a fixed value on every
+/// collect, never touching the pool. Can be made to fail or to take a while.
+#[derive(Clone)]
+pub struct StaticCollector {
+    name: &'static str,
+    gauge: IntGauge,
+    value: i64,
+    delay: Option<Duration>,
+    fail: bool,
+}
+
+impl StaticCollector {
+    /// Create a collector exposing `test_<name>` set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` isn't a valid metric name.
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    pub fn new(name: &'static str, value: i64) -> Self {
+        Self {
+            name,
+            gauge: IntGauge::new(format!("test_{name}"), "Static test gauge")
+                .expect("valid test gauge"),
+            value,
+            delay: None,
+            fail: false,
+        }
+    }
+
+    /// Sleep for `delay` before setting the value.
+    #[must_use]
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Return an error from `collect` instead of setting the value.
+    #[must_use]
+    pub const fn failing(mut self) -> Self {
+        self.fail = true;
+        self
+    }
+}
+
+impl Collector for StaticCollector {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.gauge.clone()))?;
+        Ok(())
+    }
+
+    fn collect<'a>(&'a self, _pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            if let Some(delay) = self.delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            if self.fail {
+                return Err(anyhow!("static collector '{}' failed", self.name));
+            }
+
+            self.gauge.set(self.value);
+            Ok(())
+        })
+    }
+}
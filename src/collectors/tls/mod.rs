@@ -1,7 +1,6 @@
-use crate::collectors::Collector;
+use crate::collectors::{Collector, Registrar};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::Registry;
 use sqlx::MySqlPool;
 use tracing::instrument;
 
@@ -43,7 +42,7 @@ impl Collector for TlsCollector {
         err,
         fields(collector = "tls")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.ssl_status.server_configured().clone()))?;
         registry.register(Box::new(self.ssl_status.version_info().clone()))?;
         registry.register(Box::new(self.ssl_status.cert_not_before_seconds().clone()))?;
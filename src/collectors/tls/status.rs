@@ -1,4 +1,5 @@
 use super::certificate::parse_ssl_timestamp;
+use crate::collectors::util::metric_name;
 use anyhow::Result;
 use prometheus::{Gauge, IntGauge, IntGaugeVec, Opts};
 use sqlx::MySqlPool;
@@ -24,14 +25,14 @@ impl SslStatusCollector {
     /// Panics if metric names are invalid (should not occur with static names).
     pub fn new() -> Self {
         let server_configured = IntGauge::new(
-            "mariadb_ssl_server_configured",
+            metric_name("ssl_server_configured"),
             "Whether the MariaDB server has SSL/TLS configured (1) or not (0)",
         )
         .expect("valid mariadb_ssl_server_configured metric");
 
         let version_info = IntGaugeVec::new(
             Opts::new(
-                "mariadb_ssl_version_info",
+                metric_name("ssl_version_info"),
                 "TLS version and cipher configured on the server",
             ),
             &["version", "cipher"],
@@ -39,13 +40,13 @@ impl SslStatusCollector {
         .expect("valid mariadb_ssl_version_info metric");
 
         let cert_not_before_seconds = Gauge::new(
-            "mariadb_ssl_cert_not_before_seconds",
+            metric_name("ssl_cert_not_before_seconds"),
             "Unix timestamp of the SSL certificate's not-before date",
         )
         .expect("valid mariadb_ssl_cert_not_before_seconds metric");
 
         let cert_not_after_seconds = Gauge::new(
-            "mariadb_ssl_cert_not_after_seconds",
+            metric_name("ssl_cert_not_after_seconds"),
             "Unix timestamp of the SSL certificate's not-after (expiration) date",
         )
         .expect("valid mariadb_ssl_cert_not_after_seconds metric");
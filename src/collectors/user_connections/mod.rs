@@ -0,0 +1,168 @@
+use crate::collectors::{Collector, Registrar, util::metric_name};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use prometheus::{IntGaugeVec, Opts};
+use sqlx::MySqlPool;
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
+
+const CURRENT_QUERY: &str = "SELECT USER, CAST(CONCURRENT_CONNECTIONS AS SIGNED)
+     FROM information_schema.USER_STATISTICS";
+
+/// Accounts of one user can have different limits (`user@host`); the largest one is reported.
+/// 0 means no per-account limit, so those accounts are left out.
+const LIMIT_QUERY: &str = "SELECT User, CAST(MAX(max_user_connections) AS SIGNED)
+     FROM mysql.user
+     WHERE max_user_connections > 0
+     GROUP BY User";
+
+/// Per-user connection usage and `MAX_USER_CONNECTIONS` limits (opt-in).
+///
+/// Current connections come from `USER_STATISTICS` (requires `userstat=1`), limits from
+/// `mysql.user` (requires `SELECT` on it). Each half is skipped on its own when unavailable.
+#[derive(Clone)]
+pub struct UserConnectionsCollector {
+    current: IntGaugeVec,
+    limit: IntGaugeVec,
+}
+
+impl UserConnectionsCollector {
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    /// Create a new user connections collector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if metric names are invalid (should not occur with static names).
+    pub fn new() -> Self {
+        Self {
+            current: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("user_connections_current"),
+                    "Concurrent connections per user (USER_STATISTICS.CONCURRENT_CONNECTIONS)",
+                ),
+                &["user"],
+            )
+            .expect("valid mariadb_user_connections_current metric"),
+            limit: IntGaugeVec::new(
+                Opts::new(
+                    metric_name("user_connections_limit"),
+                    "Per-user connection limit (mysql.user.max_user_connections), only users with a limit",
+                ),
+                &["user"],
+            )
+            .expect("valid mariadb_user_connections_limit metric"),
+        }
+    }
+
+    async fn collect_current(&self, pool: &MySqlPool) {
+        let status_span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = "SELECT @@userstat",
+            otel.kind = "client"
+        );
+
+        let enabled: i64 = sqlx::query_scalar("SELECT @@userstat")
+            .fetch_one(pool)
+            .instrument(status_span)
+            .await
+            .unwrap_or(0);
+
+        if enabled == 0 {
+            debug!("userstat is disabled, skipping current user connections");
+            return;
+        }
+
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = CURRENT_QUERY,
+            otel.kind = "client"
+        );
+
+        match sqlx::query_as::<_, (String, i64)>(CURRENT_QUERY)
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(rows) => {
+                for (user, connections) in rows {
+                    self.current
+                        .with_label_values(&[user.as_str()])
+                        .set(connections);
+                }
+            }
+            Err(e) => debug!(error = %e, "USER_STATISTICS not readable; skipping"),
+        }
+    }
+
+    async fn collect_limits(&self, pool: &MySqlPool) {
+        let span = info_span!(
+            "db.query",
+            db.system = "mysql",
+            db.operation = "SELECT",
+            db.statement = LIMIT_QUERY,
+            otel.kind = "client"
+        );
+
+        match sqlx::query_as::<_, (String, i64)>(LIMIT_QUERY)
+            .fetch_all(pool)
+            .instrument(span)
+            .await
+        {
+            Ok(rows) => {
+                for (user, limit) in rows {
+                    self.limit.with_label_values(&[user.as_str()]).set(limit);
+                }
+            }
+            Err(e) => {
+                debug!(error = %e, "mysql.user not readable; skipping user connection limits");
+            }
+        }
+    }
+}
+
+impl Default for UserConnectionsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collector for UserConnectionsCollector {
+    fn name(&self) -> &'static str {
+        "user_connections"
+    }
+
+    #[instrument(
+        skip(self, registry),
+        level = "info",
+        err,
+        fields(collector = "user_connections")
+    )]
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
+        registry.register(Box::new(self.current.clone()))?;
+        registry.register(Box::new(self.limit.clone()))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, pool), level = "info", err, fields(collector = "user_connections", otel.kind = "internal"))]
+    fn collect<'a>(&'a self, pool: &'a MySqlPool) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            // Reset so dropped users and removed limits disappear
+            self.current.reset();
+            self.limit.reset();
+
+            self.collect_current(pool).await;
+            self.collect_limits(pool).await;
+
+            Ok(())
+        })
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+}
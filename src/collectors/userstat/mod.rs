@@ -1,7 +1,10 @@
-use crate::collectors::Collector;
+use crate::collectors::{
+    Collector, Registrar,
+    util::{is_mysql, is_userstat_split_host, metric_name},
+};
 use anyhow::Result;
 use futures::future::BoxFuture;
-use prometheus::{IntGaugeVec, Opts, Registry};
+use prometheus::{IntGaugeVec, Opts};
 use sqlx::MySqlPool;
 use tracing::{debug, info_span, instrument};
 use tracing_futures::Instrument as _;
@@ -18,6 +21,8 @@ pub struct UserStatCollector {
     rows_deleted_total: IntGaugeVec,
     rows_inserted_total: IntGaugeVec,
     rows_updated_total: IntGaugeVec,
+    /// `--userstat.split-host`: label `user@host` as `user` and `host`
+    split_host: bool,
 }
 
 impl UserStatCollector {
@@ -29,46 +34,64 @@ impl UserStatCollector {
     ///
     /// Panics if metric names are invalid (should not occur with static names).
     pub fn new() -> Self {
+        let split_host = is_userstat_split_host();
+        let labels: &[&str] = if split_host {
+            &["user", "host"]
+        } else {
+            &["user"]
+        };
         let gvec = |name: &str, help: &str| {
-            IntGaugeVec::new(Opts::new(name, help), &["user"])
-                .expect("valid userstat metric")
+            IntGaugeVec::new(Opts::new(name, help), labels).expect("valid userstat metric")
         };
 
         Self {
             connections_total: gvec(
-                "mariadb_info_schema_userstats_connections_total",
+                &metric_name("info_schema_userstats_connections_total"),
                 "Total connections per user (user_statistics)",
             ),
             bytes_received_total: gvec(
-                "mariadb_info_schema_userstats_bytes_received_total",
+                &metric_name("info_schema_userstats_bytes_received_total"),
                 "Bytes received per user",
             ),
             bytes_sent_total: gvec(
-                "mariadb_info_schema_userstats_bytes_sent_total",
+                &metric_name("info_schema_userstats_bytes_sent_total"),
                 "Bytes sent per user",
             ),
             rows_read_total: gvec(
-                "mariadb_info_schema_userstats_rows_read_total",
+                &metric_name("info_schema_userstats_rows_read_total"),
                 "Rows read per user",
             ),
             rows_sent_total: gvec(
-                "mariadb_info_schema_userstats_rows_sent_total",
+                &metric_name("info_schema_userstats_rows_sent_total"),
                 "Rows sent per user",
             ),
             rows_deleted_total: gvec(
-                "mariadb_info_schema_userstats_rows_deleted_total",
+                &metric_name("info_schema_userstats_rows_deleted_total"),
                 "Rows deleted per user",
             ),
             rows_inserted_total: gvec(
-                "mariadb_info_schema_userstats_rows_inserted_total",
+                &metric_name("info_schema_userstats_rows_inserted_total"),
                 "Rows inserted per user",
             ),
             rows_updated_total: gvec(
-                "mariadb_info_schema_userstats_rows_updated_total",
+                &metric_name("info_schema_userstats_rows_updated_total"),
                 "Rows updated per user",
             ),
+            split_host,
         }
     }
+
+    /// Label values for a `USER_STATISTICS.USER`: the name as reported, or with
+    /// `--userstat.split-host` the part before the last `@` and the host after it (empty
+    /// when the server reports the bare user name).
+    fn label_values<'a>(&self, user: &'a str) -> Vec<&'a str> {
+        if !self.split_host {
+            return vec![user];
+        }
+
+        let (name, host) = user.rsplit_once('@').unwrap_or((user, ""));
+        vec![name, host]
+    }
 }
 
 impl Default for UserStatCollector {
@@ -88,7 +111,7 @@ impl Collector for UserStatCollector {
         err,
         fields(collector = "userstat")
     )]
-    fn register_metrics(&self, registry: &Registry) -> Result<()> {
+    fn register_metrics(&self, registry: &dyn Registrar) -> Result<()> {
         registry.register(Box::new(self.connections_total.clone()))?;
         registry.register(Box::new(self.bytes_received_total.clone()))?;
         registry.register(Box::new(self.bytes_sent_total.clone()))?;
@@ -113,6 +136,12 @@ impl Collector for UserStatCollector {
             self.rows_inserted_total.reset();
             self.rows_updated_total.reset();
 
+            // MySQL has no @@userstat, the query would only fail
+            if is_mysql() {
+                debug!("userstat is not available on MySQL; skipping collection");
+                return Ok(());
+            }
+
             // Check userstat status.
             let status_span = info_span!(
                 "db.query",
@@ -181,15 +210,15 @@ impl Collector for UserStatCollector {
             .await?;
 
             for (user, total_conn, bytes_recv, bytes_sent, rows_read, rows_sent, rows_del, rows_ins, rows_upd, _, _, _) in rows {
-                let u = user.as_str();
-                self.connections_total.with_label_values(&[u]).set(total_conn);
-                self.bytes_received_total.with_label_values(&[u]).set(bytes_recv);
-                self.bytes_sent_total.with_label_values(&[u]).set(bytes_sent);
-                self.rows_read_total.with_label_values(&[u]).set(rows_read);
-                self.rows_sent_total.with_label_values(&[u]).set(rows_sent);
-                self.rows_deleted_total.with_label_values(&[u]).set(rows_del);
-                self.rows_inserted_total.with_label_values(&[u]).set(rows_ins);
-                self.rows_updated_total.with_label_values(&[u]).set(rows_upd);
+                let u = self.label_values(&user);
+                self.connections_total.with_label_values(&u).set(total_conn);
+                self.bytes_received_total.with_label_values(&u).set(bytes_recv);
+                self.bytes_sent_total.with_label_values(&u).set(bytes_sent);
+                self.rows_read_total.with_label_values(&u).set(rows_read);
+                self.rows_sent_total.with_label_values(&u).set(rows_sent);
+                self.rows_deleted_total.with_label_values(&u).set(rows_del);
+                self.rows_inserted_total.with_label_values(&u).set(rows_ins);
+                self.rows_updated_total.with_label_values(&u).set(rows_upd);
             }
 
             Ok(())
@@ -200,3 +229,22 @@ impl Collector for UserStatCollector {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_values() {
+        let collector = UserStatCollector::new();
+        assert_eq!(collector.label_values("app@10.0.0.%"), vec!["app@10.0.0.%"]);
+
+        let split = UserStatCollector {
+            split_host: true,
+            ..UserStatCollector::new()
+        };
+        assert_eq!(split.label_values("app@10.0.0.%"), vec!["app", "10.0.0.%"]);
+        assert_eq!(split.label_values("app"), vec!["app", ""]);
+        assert_eq!(split.label_values("we@ird@host"), vec!["we@ird", "host"]);
+    }
+}
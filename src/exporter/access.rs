@@ -0,0 +1,186 @@
+use anyhow::{Result, anyhow};
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, Extension},
+    http::{HeaderMap, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ipnet::IpNet;
+use std::{net::IpAddr, net::SocketAddr, sync::Arc};
+use tracing::{debug, warn};
+
+/// Client IP allowlist for the metrics endpoint.
+///
+/// `X-Forwarded-For` is only honored when the connection comes from a trusted proxy, so
+/// clients can't spoof their address by sending the header themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessConfig {
+    pub allowed_cidrs: Vec<IpNet>,
+    pub trusted_proxies: Vec<IpNet>,
+}
+
+impl AccessConfig {
+    /// Whether an allowlist is configured; without one every client is allowed.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        !self.allowed_cidrs.is_empty()
+    }
+
+    fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|net| net.contains(&ip))
+    }
+
+    /// Resolve the real client address.
+    ///
+    /// Walks `X-Forwarded-For` from right to left while the hops are trusted proxies; the
+    /// first untrusted hop is the client. Returns `None` if a hop can't be parsed.
+    #[must_use]
+    pub fn client_ip(&self, peer: IpAddr, headers: &HeaderMap) -> Option<IpAddr> {
+        let mut client = peer.to_canonical();
+
+        if !self.is_trusted(client) {
+            return Some(client);
+        }
+
+        let hops: Vec<&str> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .map(str::trim)
+            .filter(|hop| !hop.is_empty())
+            .collect();
+
+        for hop in hops.iter().rev() {
+            client = hop.parse::<IpAddr>().ok()?.to_canonical();
+            if !self.is_trusted(client) {
+                break;
+            }
+        }
+
+        Some(client)
+    }
+
+    /// Whether the client may scrape.
+    #[must_use]
+    pub fn is_allowed(&self, client: IpAddr) -> bool {
+        !self.is_enabled() || self.allowed_cidrs.iter().any(|net| net.contains(&client))
+    }
+}
+
+/// Parse a CIDR (`10.0.0.0/8`) or a bare address (`10.0.0.1`, taken as a single host).
+///
+/// # Errors
+///
+/// Returns an error if the value is neither a CIDR nor an IP address
+pub fn parse_cidr(value: &str) -> Result<IpNet> {
+    let value = value.trim();
+
+    value
+        .parse::<IpNet>()
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .map_err(|_| anyhow!("invalid CIDR or IP address: '{value}'"))
+}
+
+/// Middleware rejecting clients outside `--web.allowed-cidrs` with 403.
+pub async fn enforce(
+    Extension(access): Extension<Arc<AccessConfig>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if !access.is_enabled() {
+        return next.run(req).await;
+    }
+
+    let client = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .and_then(|ConnectInfo(addr)| access.client_ip(addr.ip(), req.headers()));
+
+    match client {
+        Some(ip) if access.is_allowed(ip) => {
+            debug!(client = %ip, "client allowed");
+            next.run(req).await
+        }
+        Some(ip) => {
+            warn!(client = %ip, "client not in --web.allowed-cidrs");
+            StatusCode::FORBIDDEN.into_response()
+        }
+        None => {
+            warn!("could not determine client address");
+            StatusCode::FORBIDDEN.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn access(allowed: &[&str], trusted: &[&str]) -> AccessConfig {
+        AccessConfig {
+            allowed_cidrs: allowed.iter().map(|c| parse_cidr(c).unwrap()).collect(),
+            trusted_proxies: trusted.iter().map(|c| parse_cidr(c).unwrap()).collect(),
+        }
+    }
+
+    fn forwarded(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_parse_cidr() {
+        assert_eq!(parse_cidr("10.0.0.0/8").unwrap().to_string(), "10.0.0.0/8");
+        assert_eq!(parse_cidr(" 10.0.0.1 ").unwrap().to_string(), "10.0.0.1/32");
+        assert_eq!(parse_cidr("::1").unwrap().to_string(), "::1/128");
+        assert!(parse_cidr("10.0.0.0/33").is_err());
+        assert!(parse_cidr("not-an-ip").is_err());
+    }
+
+    #[test]
+    fn test_forwarded_for_ignored_from_untrusted_peer() {
+        let access = access(&["192.168.0.0/16"], &["10.0.0.0/8"]);
+        let peer: IpAddr = "203.0.113.7".parse().unwrap();
+
+        // A client can't claim an allowed address by sending the header itself
+        let client = access.client_ip(peer, &forwarded("192.168.1.10")).unwrap();
+        assert_eq!(client, peer);
+        assert!(!access.is_allowed(client));
+    }
+
+    #[test]
+    fn test_forwarded_for_from_trusted_proxies() {
+        let access = access(&["192.168.0.0/16"], &["10.0.0.0/8"]);
+        let peer: IpAddr = "10.0.0.2".parse().unwrap();
+
+        // Spoofed leftmost entry, real client appended by the first proxy, then a second proxy
+        let client = access
+            .client_ip(peer, &forwarded("1.2.3.4, 192.168.1.10, 10.0.0.1"))
+            .unwrap();
+        assert_eq!(client, "192.168.1.10".parse::<IpAddr>().unwrap());
+        assert!(access.is_allowed(client));
+
+        assert!(access.client_ip(peer, &forwarded("garbage")).is_none());
+    }
+
+    #[test]
+    fn test_ipv4_mapped_peer_matches_ipv4_cidr() {
+        let access = access(&["127.0.0.0/8"], &[]);
+        let peer: IpAddr = "::ffff:127.0.0.1".parse().unwrap();
+
+        let client = access.client_ip(peer, &HeaderMap::new()).unwrap();
+        assert!(access.is_allowed(client));
+    }
+
+    #[test]
+    fn test_no_allowlist_allows_everyone() {
+        let access = AccessConfig::default();
+        assert!(!access.is_enabled());
+        assert!(access.is_allowed("203.0.113.7".parse().unwrap()));
+    }
+}
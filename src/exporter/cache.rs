@@ -0,0 +1,134 @@
+//! `--cache-ttl`: serve the last scrape to requests arriving within the TTL, so the number of
+//! Prometheus servers (or their scrape interval) doesn't set the load on the database.
+
+use prometheus::proto::MetricFamily;
+use std::{future::Future, sync::Arc, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+use tracing::debug;
+
+type Snapshot = Arc<Vec<MetricFamily>>;
+
+/// The last gathered metric families and when they were collected.
+pub struct ScrapeCache {
+    ttl: Duration,
+    snapshot: Mutex<Option<(Instant, Snapshot)>>,
+}
+
+impl ScrapeCache {
+    /// A cache keeping snapshots for `ttl`; `Duration::ZERO` disables it.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            snapshot: Mutex::new(None),
+        }
+    }
+
+    /// Return the cached snapshot while it's younger than the TTL, otherwise run `collect`
+    /// and cache its result.
+    ///
+    /// The lock is held while collecting, so requests arriving meanwhile wait for that one
+    /// collection (single-flight) and are answered from it instead of starting their own.
+    /// When disabled every call collects, concurrently, as without a cache.
+    pub async fn get_or_collect<F, Fut>(&self, collect: F) -> Snapshot
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Vec<MetricFamily>>,
+    {
+        if self.ttl.is_zero() {
+            return Arc::new(collect().await);
+        }
+
+        let mut snapshot = self.snapshot.lock().await;
+
+        if let Some((collected_at, families)) = snapshot.as_ref()
+            && collected_at.elapsed() < self.ttl
+        {
+            debug!(
+                age_secs = collected_at.elapsed().as_secs_f64(),
+                "Serving cached metrics"
+            );
+            return Arc::clone(families);
+        }
+
+        let families = Arc::new(collect().await);
+        *snapshot = Some((Instant::now(), Arc::clone(&families)));
+
+        families
+    }
+}
+
+impl Default for ScrapeCache {
+    fn default() -> Self {
+        Self::new(Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn family(name: &str) -> Vec<MetricFamily> {
+        let mut family = MetricFamily::default();
+        family.set_name(name.to_string());
+        vec![family]
+    }
+
+    #[tokio::test]
+    async fn test_disabled_cache_always_collects() {
+        let cache = ScrapeCache::default();
+        let calls = AtomicUsize::new(0);
+
+        for _ in 0..3 {
+            cache
+                .get_or_collect(|| async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    family("a")
+                })
+                .await;
+        }
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cache_serves_snapshot_until_stale() {
+        let cache = ScrapeCache::new(Duration::from_secs(10));
+        let calls = AtomicUsize::new(0);
+        let collect = || async {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            family(&format!("scrape_{n}"))
+        };
+
+        let first = cache.get_or_collect(collect).await;
+        tokio::time::advance(Duration::from_secs(5)).await;
+        let cached = cache.get_or_collect(collect).await;
+        assert!(Arc::ptr_eq(&first, &cached));
+
+        tokio::time::advance(Duration::from_secs(6)).await;
+        let fresh = cache.get_or_collect(collect).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(fresh.first().map(MetricFamily::name), Some("scrape_1"));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_share_one_collect() {
+        let cache = ScrapeCache::new(Duration::from_mins(1));
+        let calls = AtomicUsize::new(0);
+        let collect = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            family("a")
+        };
+
+        let (a, b, c) = tokio::join!(
+            cache.get_or_collect(collect),
+            cache.get_or_collect(collect),
+            cache.get_or_collect(collect)
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(Arc::ptr_eq(&a, &b) && Arc::ptr_eq(&b, &c));
+    }
+}
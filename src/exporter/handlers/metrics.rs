@@ -1,37 +1,74 @@
-use crate::collectors::registry::CollectorRegistry;
+use crate::{
+    collectors::{
+        exporter::InflightScrape,
+        registry::{encode_families_stream, merge_families},
+    },
+    exporter::{Target, cache::ScrapeCache},
+};
 use axum::{
+    body::Body,
     extract::Extension,
     http::{HeaderMap, HeaderValue, StatusCode},
     response::IntoResponse,
 };
-use sqlx::MySqlPool;
-use tracing::{debug, error, instrument};
+use futures::future::join_all;
+use prometheus::proto::MetricFamily;
+use std::{sync::Arc, time::Instant};
+use tracing::{debug, info_span, instrument};
+use tracing_futures::Instrument as _;
 
-#[instrument(skip(pool, registry), fields(http.route="/metrics"))]
+#[instrument(skip(targets, cache), fields(http.route="/metrics"))]
 pub async fn metrics(
-    Extension(pool): Extension<MySqlPool>,
-    Extension(registry): Extension<CollectorRegistry>,
+    Extension(targets): Extension<Arc<Vec<Target>>>,
+    Extension(cache): Extension<Arc<ScrapeCache>>,
 ) -> impl IntoResponse {
+    // Until the response is handed to axum; reported by each target's scraper when collecting
+    let _inflight = InflightScrape::enter();
+
     let mut headers = HeaderMap::new();
     headers.insert(
         "content-type",
         HeaderValue::from_static("text/plain; charset=utf-8"),
     );
 
-    match registry.collect_all(&pool).await {
-        Ok(metrics) => {
+    let families = cache
+        .get_or_collect(|| async {
+            let start = Instant::now();
+            let families = collect_targets(&targets).await;
+
+            // Lands in the next exposition, since this one is already gathered
+            let elapsed = start.elapsed();
+            for target in targets.iter() {
+                target.registry.observe_scrape_duration(elapsed);
+            }
+
             debug!("Successfully collected metrics");
-            (StatusCode::OK, headers, metrics)
-        }
-        Err(e) => {
-            error!("Failed to collect metrics: {}", e);
-            (
-                StatusCode::OK,
-                headers,
-                format!("# Error collecting metrics: {e}\nmariadb_up 0\n"),
-            )
-        }
+            families
+        })
+        .await;
+
+    // Encoded family by family as the chunked body is sent, instead of into one String
+    (
+        StatusCode::OK,
+        headers,
+        Body::from_stream(encode_families_stream(families)),
+    )
+}
+
+/// Scrape every target concurrently and merge them into one exposition.
+async fn collect_targets(targets: &[Target]) -> Vec<MetricFamily> {
+    if let [target] = targets {
+        return target.registry.gather_all(&target.pool.load_full()).await;
     }
+
+    let gathered = join_all(targets.iter().map(|target| async move {
+        let span = info_span!("target.collect", instance = %target.instance);
+        let pool = target.pool.load_full();
+        target.registry.gather_all(&pool).instrument(span).await
+    }))
+    .await;
+
+    merge_families(gathered)
 }
 
 #[cfg(test)]
@@ -0,0 +1 @@
+pub mod perf_schema;
@@ -0,0 +1,46 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::account_statements::AccountStatementsCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_account_statements_collector_registers_without_error() -> Result<()> {
+    let collector = AccountStatementsCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_account_statements_collector_bounds_accounts() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = AccountStatementsCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Must succeed whether or not performance_schema is enabled
+    collector.collect(&pool).await?;
+
+    for family in registry.gather().iter().filter(|f| {
+        f.name()
+            .starts_with("mariadb_perf_schema_account_statements")
+    }) {
+        assert!(family.get_metric().len() <= 25);
+        for metric in family.get_metric() {
+            let labels: Vec<&str> = metric
+                .get_label()
+                .iter()
+                .map(prometheus::proto::LabelPair::name)
+                .collect();
+            assert_eq!(labels, vec!["host", "user"]);
+        }
+    }
+
+    pool.close().await;
+    Ok(())
+}
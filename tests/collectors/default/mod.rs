@@ -1,2 +1,3 @@
 pub mod status;
+pub mod time;
 pub mod version;
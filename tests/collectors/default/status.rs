@@ -117,44 +117,46 @@ async fn test_global_status_collector_com_metrics() -> Result<()> {
 
     let metric_families = registry.gather();
 
-    // Check that Com_ metrics exist
-    let com_metrics = vec![
-        "mariadb_global_status_com_select",
-        "mariadb_global_status_com_insert",
-        "mariadb_global_status_com_update",
-        "mariadb_global_status_com_delete",
-        "mariadb_global_status_com_replace",
-    ];
-
-    for metric_name in com_metrics {
-        let found = metric_families.iter().any(|m| m.name() == metric_name);
+    // Com_ statistics are one counter labelled by command
+    let commands = metric_families
+        .iter()
+        .find(|m| m.name() == "mariadb_global_status_commands_total");
+
+    assert!(commands.is_some(), "Com_ counters should exist");
+    if let Some(family) = commands {
+        let command = |name: &str| {
+            family.get_metric().iter().find(|m| {
+                m.get_label()
+                    .iter()
+                    .any(|l| l.name() == "command" && l.value() == name)
+            })
+        };
+
+        for name in ["select", "insert", "update", "delete", "replace"] {
+            assert!(command(name).is_some(), "Com_{name} counter should exist");
+        }
+
+        // Verify Com_select has a non-zero value after our SELECT queries
+        let value = command("select").map_or(0.0, |m| m.get_counter().value());
         assert!(
-            found,
-            "Com_ metric {} should exist. Found: {:?}",
-            metric_name,
-            metric_families
-                .iter()
-                .map(prometheus::proto::MetricFamily::name)
-                .collect::<Vec<_>>()
+            value >= 2.0,
+            "Com_select should be >= 2 after executing 2 SELECT statements, got: {value}"
         );
     }
 
-    // Verify Com_select has a non-zero value after our SELECT queries
-    let com_select = metric_families
-        .iter()
-        .find(|m| m.name() == "mariadb_global_status_com_select");
-
-    assert!(com_select.is_some(), "Com_select metric should exist");
-    if let Some(metric) = com_select {
-        let metrics = metric.get_metric();
+    // The deprecated per-command gauges are still exported
+    for name in [
+        "select",
+        "insert",
+        "update",
+        "delete",
+        "replace",
+        "admin_commands",
+    ] {
+        let gauge = format!("mariadb_global_status_com_{name}");
         assert!(
-            !metrics.is_empty(),
-            "Com_select should have at least one sample"
-        );
-        let value = metrics[0].get_gauge().value.unwrap_or(0.0);
-        assert!(
-            value >= 2.0,
-            "Com_select should be >= 2 after executing 2 SELECT statements, got: {value}"
+            metric_families.iter().any(|m| m.name() == gauge),
+            "{gauge} should still exist"
         );
     }
 
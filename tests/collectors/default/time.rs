@@ -0,0 +1,57 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::default::time::TimeCollector;
+use prometheus::Registry;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[tokio::test]
+async fn test_time_collector_registers_without_error() -> Result<()> {
+    let collector = TimeCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_time_collector_reports_server_clock() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+    let collector = TimeCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let metric_families = registry.gather();
+
+    let server_time = metric_families
+        .iter()
+        .find(|m| m.name() == "mariadb_server_time_seconds")
+        .and_then(|m| m.get_metric().first())
+        .map(|m| m.get_gauge().value());
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs_f64();
+    let server_time = server_time.expect("server_time_seconds metric should exist");
+    assert!(
+        (server_time - now).abs() < 300.0,
+        "server clock {server_time} too far from local clock {now}"
+    );
+
+    assert!(
+        metric_families
+            .iter()
+            .any(|m| m.name() == "mariadb_global_variables_time_zone_offset_seconds"),
+        "time_zone_offset_seconds metric should exist"
+    );
+    assert!(
+        metric_families
+            .iter()
+            .any(|m| m.name() == "mariadb_global_variables_time_zone_info"),
+        "time_zone_info metric should exist"
+    );
+
+    pool.close().await;
+    Ok(())
+}
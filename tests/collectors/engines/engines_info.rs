@@ -0,0 +1,44 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::engines::EnginesCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_engines_collector_registers_without_error() -> Result<()> {
+    let collector = EnginesCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_engines_collector_reports_innodb() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = EnginesCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let metrics = registry.gather();
+    let supported = metrics
+        .iter()
+        .find(|m| m.name() == "mariadb_engine_supported")
+        .expect("mariadb_engine_supported should be present");
+
+    // InnoDB is available on every supported server version
+    let innodb_available = supported.get_metric().iter().any(|m| {
+        m.get_label()
+            .iter()
+            .any(|l| l.name() == "engine" && l.value() == "InnoDB")
+            && (m.get_gauge().value() - 1.0).abs() < f64::EPSILON
+    });
+    assert!(innodb_available, "InnoDB should be reported as available");
+
+    pool.close().await;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::file_io::FileIoCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_file_io_collector_registers_without_error() -> Result<()> {
+    let collector = FileIoCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_file_io_collector_collects() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = FileIoCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Must succeed whether or not performance_schema is enabled
+    let result = collector.collect(&pool).await;
+    assert!(
+        result.is_ok(),
+        "Collector should handle a missing performance_schema gracefully"
+    );
+
+    let families = registry.gather();
+    for family in families
+        .iter()
+        .filter(|f| f.name().starts_with("mariadb_perf_schema_file_io_"))
+    {
+        assert!(
+            family.get_metric().len() <= 20,
+            "{} should be limited to the top event names",
+            family.name()
+        );
+    }
+
+    pool.close().await;
+    Ok(())
+}
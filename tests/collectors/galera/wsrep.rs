@@ -0,0 +1,35 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::galera::GaleraCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_galera_collector_registers_without_error() -> Result<()> {
+    let collector = GaleraCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_galera_collector_handles_non_galera_server() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = GaleraCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // The test server runs without the wsrep provider; collection must still succeed
+    let result = collector.collect(&pool).await;
+    assert!(
+        result.is_ok(),
+        "Collector should handle servers without Galera gracefully"
+    );
+
+    pool.close().await;
+    Ok(())
+}
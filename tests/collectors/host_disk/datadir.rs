@@ -0,0 +1,66 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::host_disk::HostDiskCollector;
+use mariadb_exporter::collectors::util::set_datadir;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_host_disk_collector_registers_without_error() -> Result<()> {
+    let collector = HostDiskCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_host_disk_collector_reports_datadir_filesystem() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let datadir = std::env::temp_dir();
+    set_datadir(datadir.clone());
+
+    let collector = HostDiskCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let families = registry.gather();
+    let value = |name: &str| {
+        families
+            .iter()
+            .filter(|f| f.name() == name)
+            .flat_map(|f| f.get_metric().to_vec())
+            .find(|m| {
+                m.get_label()
+                    .iter()
+                    .any(|l| l.name() == "path" && l.value() == datadir.to_string_lossy())
+            })
+            .map(|m| m.get_gauge().value())
+    };
+
+    // Containers may not list the mount, so only check the values when there is one
+    if let (Some(free), Some(total)) = (
+        value("mariadb_datadir_free_bytes"),
+        value("mariadb_datadir_total_bytes"),
+    ) {
+        assert!(total > 0.0);
+        assert!(free <= total);
+    }
+
+    // @@tmpdir is only reported when the server's path also exists on this host
+    for family in families
+        .iter()
+        .filter(|f| f.name().starts_with("mariadb_tmpdir_"))
+    {
+        for metric in family.get_metric() {
+            assert!(metric.get_gauge().value() >= 0.0);
+        }
+    }
+
+    pool.close().await;
+    Ok(())
+}
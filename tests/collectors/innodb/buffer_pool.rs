@@ -0,0 +1,35 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::innodb::buffer_pool::BufferPoolStatsCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_buffer_pool_stats_collects_per_instance() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = BufferPoolStatsCollector::new();
+    let registry = Registry::new();
+
+    registry.register(Box::new(collector.pages_total().clone()))?;
+    registry.register(Box::new(collector.pages_data().clone()))?;
+
+    collector.collect(&pool).await?;
+
+    let metric_families = registry.gather();
+    let pages_total = metric_families
+        .iter()
+        .find(|m| m.name() == "mariadb_innodb_buffer_pool_instance_pages_total");
+
+    // Only present when the test user can read INNODB_BUFFER_POOL_STATS (PROCESS)
+    if let Some(mf) = pages_total {
+        assert!(!mf.get_metric().is_empty());
+        assert!(
+            mf.get_metric()
+                .iter()
+                .all(|m| m.get_label().iter().any(|l| l.name() == "pool_id"))
+        );
+    }
+
+    pool.close().await;
+    Ok(())
+}
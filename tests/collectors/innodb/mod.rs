@@ -1 +1,3 @@
+pub mod buffer_pool;
 pub mod status;
+pub mod tablespaces;
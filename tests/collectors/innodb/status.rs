@@ -15,13 +15,21 @@ async fn test_innodb_collector_registers_without_error() -> Result<()> {
     let metric_families = registry.gather();
 
     let expected_metrics = vec![
+        "mariadb_innodb_status_sample_interval_seconds",
         "mariadb_innodb_lsn_current",
         "mariadb_innodb_lsn_flushed",
         "mariadb_innodb_lsn_checkpoint",
         "mariadb_innodb_checkpoint_age_bytes",
+        "mariadb_innodb_checkpoint_age_ratio",
         "mariadb_innodb_active_transactions",
+        "mariadb_innodb_oldest_transaction_seconds",
         "mariadb_innodb_semaphore_waits_total",
         "mariadb_innodb_semaphore_wait_time_ms_total",
+        "mariadb_innodb_semaphore_wait_time_seconds_total",
+        "mariadb_innodb_mutex_os_waits",
+        "mariadb_innodb_rw_shared_os_waits",
+        "mariadb_innodb_rw_excl_os_waits",
+        "mariadb_innodb_adaptive_hash_index_enabled",
         "mariadb_innodb_adaptive_hash_searches_total",
         "mariadb_innodb_adaptive_hash_searches_btree_total",
     ];
@@ -0,0 +1,28 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::innodb::tablespaces::TablespacesCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_tablespaces_collector_bounded_to_top_20() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = TablespacesCollector::new();
+    let registry = Registry::new();
+
+    registry.register(Box::new(collector.file_size_bytes().clone()))?;
+    registry.register(Box::new(collector.allocated_size_bytes().clone()))?;
+
+    collector.collect(&pool).await?;
+
+    let metric_families = registry.gather();
+    if let Some(mf) = metric_families
+        .iter()
+        .find(|m| m.name() == "mariadb_innodb_tablespace_file_size_bytes")
+    {
+        assert!(mf.get_metric().len() <= 20);
+    }
+
+    pool.close().await;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::innodb_tablestats::InnodbTablestatsCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_innodb_tablestats_collector_registers_without_error() -> Result<()> {
+    let collector = InnodbTablestatsCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_innodb_tablestats_collector_reports_modified_table() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    // Skip when the test user can't create tables
+    for statement in [
+        "CREATE DATABASE IF NOT EXISTS exporter_test",
+        "CREATE TABLE IF NOT EXISTS exporter_test.exporter_tablestats (id INT PRIMARY KEY) ENGINE=InnoDB",
+        "INSERT IGNORE INTO exporter_test.exporter_tablestats VALUES (1), (2), (3)",
+    ] {
+        if sqlx::query(statement).execute(&pool).await.is_err() {
+            pool.close().await;
+            return Ok(());
+        }
+    }
+
+    let collector = InnodbTablestatsCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Must succeed whether or not the server has INNODB_SYS_TABLESTATS
+    collector.collect(&pool).await?;
+
+    let modified = registry
+        .gather()
+        .iter()
+        .filter(|f| f.name() == "mariadb_innodb_table_modified_counter")
+        .flat_map(|f| f.get_metric().to_vec())
+        .find(|m| {
+            m.get_label()
+                .iter()
+                .any(|l| l.name() == "table" && l.value() == "exporter_tablestats")
+        })
+        .map(|m| m.get_gauge().value());
+
+    let _ = sqlx::query("DROP TABLE IF EXISTS exporter_test.exporter_tablestats")
+        .execute(&pool)
+        .await;
+    pool.close().await;
+
+    // Statistics may already have been recalculated, resetting the counter
+    if let Some(modified) = modified {
+        assert!(modified > 0.0);
+    }
+    Ok(())
+}
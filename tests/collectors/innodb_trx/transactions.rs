@@ -0,0 +1,66 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::innodb_trx::InnodbTrxCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_innodb_trx_collector_registers_without_error() -> Result<()> {
+    let collector = InnodbTrxCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_innodb_trx_collector_reports_open_transaction() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    // Skip when the test user can't create tables
+    for statement in [
+        "CREATE DATABASE IF NOT EXISTS exporter_test",
+        "CREATE TABLE IF NOT EXISTS exporter_test.exporter_trx (id INT PRIMARY KEY) ENGINE=InnoDB",
+    ] {
+        if sqlx::query(statement).execute(&pool).await.is_err() {
+            pool.close().await;
+            return Ok(());
+        }
+    }
+
+    // Keep a transaction open, holding a row lock, while collecting
+    let mut trx = pool.begin().await?;
+    sqlx::query("INSERT INTO exporter_test.exporter_trx (id) VALUES (1)")
+        .execute(&mut *trx)
+        .await?;
+
+    let collector = InnodbTrxCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    trx.rollback().await?;
+
+    let families = registry.gather();
+    let value = |name: &str| {
+        families
+            .iter()
+            .find(|f| f.name() == name)
+            .and_then(|f| f.get_metric().first())
+            .map(|m| m.get_gauge().value())
+    };
+
+    let running = value("mariadb_innodb_trx_running");
+    let rows_locked = value("mariadb_innodb_trx_rows_locked_total");
+
+    let _ = sqlx::query("DROP TABLE IF EXISTS exporter_test.exporter_trx")
+        .execute(&pool)
+        .await;
+    pool.close().await;
+
+    assert!(running.is_some_and(|running| running >= 1.0));
+    assert!(rows_locked.is_some());
+    Ok(())
+}
@@ -1,12 +1,25 @@
+pub mod account_statements;
 pub mod common;
 pub mod connection;
 pub mod default;
+pub mod engines;
+pub mod file_io;
+pub mod galera;
+pub mod host_disk;
 pub mod innodb;
+pub mod innodb_tablestats;
+pub mod innodb_trx;
 pub mod locks;
 pub mod metadata;
+pub mod perf_schema_setup;
 pub mod query_response_time;
 pub mod replication;
 pub mod schema;
 pub mod statements;
+pub mod status_all;
+pub mod sys;
+pub mod table_health;
+pub mod table_io;
 pub mod tls;
+pub mod user_connections;
 pub mod userstat;
@@ -0,0 +1,48 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::perf_schema_setup::PerfSchemaSetupCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_perf_schema_setup_collector_registers_without_error() -> Result<()> {
+    let collector = PerfSchemaSetupCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_perf_schema_setup_collector_collects() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = PerfSchemaSetupCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Must succeed whether or not performance_schema is enabled
+    let result = collector.collect(&pool).await;
+    assert!(
+        result.is_ok(),
+        "Collector should handle a disabled performance_schema gracefully"
+    );
+
+    let families = registry.gather();
+    for metric in families
+        .iter()
+        .filter(|f| f.name() == "mariadb_perf_schema_consumer_enabled")
+        .flat_map(|f| f.get_metric().iter())
+    {
+        let value = metric.get_gauge().value();
+        assert!(
+            value == 0.0 || (value - 1.0).abs() < f64::EPSILON,
+            "consumer_enabled should be 0 or 1, got {value}"
+        );
+    }
+
+    pool.close().await;
+    Ok(())
+}
@@ -82,3 +82,31 @@ async fn test_replication_collector_handles_privilege_errors() -> Result<()> {
     pool.close().await;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_replication_collector_reports_connected_replicas() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = ReplicationCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let metric_families = registry.gather();
+    let connected = metric_families
+        .iter()
+        .find(|m| m.name() == "mariadb_primary_connected_replicas")
+        .and_then(|m| m.get_metric().first())
+        .and_then(|m| m.get_gauge().value)
+        .ok_or_else(|| anyhow::anyhow!("mariadb_primary_connected_replicas missing"))?;
+
+    // A standalone test server has no replicas; -1 means the query wasn't permitted
+    assert!(
+        connected.abs() < f64::EPSILON || (connected + 1.0).abs() < f64::EPSILON,
+        "standalone server should report 0 (or -1) connected replicas, got {connected}"
+    );
+
+    pool.close().await;
+    Ok(())
+}
@@ -2,6 +2,7 @@ use super::super::common;
 use anyhow::Result;
 use mariadb_exporter::collectors::Collector;
 use mariadb_exporter::collectors::schema::SchemaCollector;
+use mariadb_exporter::collectors::util::SYSTEM_SCHEMAS;
 use prometheus::Registry;
 
 #[tokio::test]
@@ -94,6 +95,17 @@ async fn test_schema_collector_metrics_have_labels() -> Result<()> {
                     let has_table = labels.iter().any(|l| l.name() == "table");
                     assert!(has_schema, "Schema metrics should have 'schema' label");
                     assert!(has_table, "Schema metrics should have 'table' label");
+                    if [
+                        "mariadb_info_schema_table_size_bytes",
+                        "mariadb_info_schema_table_rows",
+                    ]
+                    .contains(&metric_family.name())
+                    {
+                        assert!(
+                            labels.iter().any(|l| l.name() == "engine"),
+                            "Table metrics should have 'engine' label"
+                        );
+                    }
                 }
             }
         }
@@ -102,3 +114,39 @@ async fn test_schema_collector_metrics_have_labels() -> Result<()> {
     pool.close().await;
     Ok(())
 }
+
+#[tokio::test]
+async fn test_schema_collector_schema_rollup_has_schema_label_only() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = SchemaCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let metrics = registry.gather();
+
+    for metric_family in &metrics {
+        if metric_family
+            .name()
+            .starts_with("mariadb_info_schema_schema_")
+        {
+            for metric in metric_family.get_metric() {
+                let labels = metric.get_label();
+                assert_eq!(labels.len(), 1, "Schema rollup should have one label");
+                assert!(
+                    labels.iter().all(|l| l.name() == "schema"),
+                    "Schema rollup should only have a 'schema' label"
+                );
+                assert!(
+                    labels.iter().all(|l| !SYSTEM_SCHEMAS.contains(&l.value())),
+                    "System schemas should be excluded"
+                );
+            }
+        }
+    }
+
+    pool.close().await;
+    Ok(())
+}
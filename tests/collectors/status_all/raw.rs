@@ -0,0 +1,42 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::status_all::StatusAllCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_status_all_collector_registers_without_error() -> Result<()> {
+    let collector = StatusAllCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_status_all_collector_reports_uptime() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = StatusAllCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let metrics = registry.gather();
+    let raw = metrics
+        .iter()
+        .find(|m| m.name() == "mariadb_global_status_raw")
+        .expect("mariadb_global_status_raw should be present");
+
+    let uptime = raw.get_metric().iter().find(|m| {
+        m.get_label()
+            .iter()
+            .any(|l| l.name() == "variable" && l.value() == "uptime")
+    });
+    assert!(uptime.is_some_and(|m| m.get_gauge().value() > 0.0));
+
+    pool.close().await;
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::sys::SysCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_sys_collector_registers_without_error() -> Result<()> {
+    let collector = SysCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sys_collector_collects() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = SysCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Without the sys schema the collector skips; with it both views are readable
+    let result = collector.collect(&pool).await;
+    assert!(result.is_ok(), "sys collector failed: {result:?}");
+
+    let families = registry.gather();
+    let has_sys = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM information_schema.schemata WHERE schema_name = 'sys'",
+    )
+    .fetch_one(&pool)
+    .await?
+        > 0;
+
+    if has_sys {
+        assert!(
+            families
+                .iter()
+                .any(|f| f.name() == "mariadb_sys_full_scan_statements_total")
+        );
+    }
+
+    pool.close().await;
+    Ok(())
+}
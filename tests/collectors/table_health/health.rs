@@ -0,0 +1,68 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::table_health::TableHealthCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_table_health_collector_registers_without_error() -> Result<()> {
+    let collector = TableHealthCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_health_collector_reports_checked_table() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    // Skip when the test user can't create tables
+    for statement in [
+        "CREATE DATABASE IF NOT EXISTS exporter_test",
+        "CREATE TABLE IF NOT EXISTS exporter_test.exporter_checked (id INT PRIMARY KEY) ENGINE=Aria",
+        "CHECK TABLE exporter_test.exporter_checked",
+    ] {
+        if sqlx::query(statement).execute(&pool).await.is_err() {
+            pool.close().await;
+            return Ok(());
+        }
+    }
+
+    let collector = TableHealthCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+    collector.collect(&pool).await?;
+
+    let families = registry.gather();
+    let check_time = families
+        .iter()
+        .filter(|f| f.name() == "mariadb_info_schema_table_check_time_seconds")
+        .flat_map(|f| f.get_metric().to_vec())
+        .find(|m| {
+            m.get_label()
+                .iter()
+                .any(|l| l.name() == "table" && l.value() == "exporter_checked")
+        })
+        .map(|m| m.get_gauge().value());
+
+    let corrupted = families
+        .iter()
+        .find(|f| f.name() == "mariadb_innodb_corrupted_indexes")
+        .and_then(|f| f.get_metric().first())
+        .map(|m| m.get_gauge().value());
+
+    let _ = sqlx::query("DROP TABLE IF EXISTS exporter_test.exporter_checked")
+        .execute(&pool)
+        .await;
+    pool.close().await;
+
+    // Only when more tables were checked than the series limit is the table left out
+    if let Some(check_time) = check_time {
+        assert!(check_time > 0.0);
+    }
+    assert_eq!(corrupted, Some(0.0), "a test server has no corrupt indexes");
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::table_io::TableIoCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_table_io_collector_registers_without_error() -> Result<()> {
+    let collector = TableIoCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_io_collector_skips_system_schemas() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    let collector = TableIoCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Must succeed whether or not performance_schema is enabled
+    collector.collect(&pool).await?;
+
+    for family in registry
+        .gather()
+        .iter()
+        .filter(|f| f.name().starts_with("mariadb_perf_schema_table_io_waits"))
+    {
+        for metric in family.get_metric() {
+            let schema = metric
+                .get_label()
+                .iter()
+                .find(|l| l.name() == "schema")
+                .map(|l| l.value().to_string())
+                .unwrap_or_default();
+            assert!(
+                !["mysql", "information_schema", "performance_schema", "sys"]
+                    .contains(&schema.as_str()),
+                "system schema {schema} should not be reported"
+            );
+        }
+    }
+
+    pool.close().await;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use super::super::common;
+use anyhow::Result;
+use mariadb_exporter::collectors::Collector;
+use mariadb_exporter::collectors::user_connections::UserConnectionsCollector;
+use prometheus::Registry;
+
+#[tokio::test]
+async fn test_user_connections_collector_registers_without_error() -> Result<()> {
+    let collector = UserConnectionsCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_user_connections_collector_reports_limits() -> Result<()> {
+    let pool = common::create_test_pool().await?;
+
+    // Skip when the test user can't manage accounts
+    if sqlx::query("CREATE USER IF NOT EXISTS 'exporter_limited'@'%' WITH MAX_USER_CONNECTIONS 7")
+        .execute(&pool)
+        .await
+        .is_err()
+    {
+        pool.close().await;
+        return Ok(());
+    }
+
+    let collector = UserConnectionsCollector::new();
+    let registry = Registry::new();
+
+    collector.register_metrics(&registry)?;
+
+    // Must succeed whether or not userstat is enabled
+    collector.collect(&pool).await?;
+
+    let limit = registry
+        .gather()
+        .iter()
+        .filter(|f| f.name() == "mariadb_user_connections_limit")
+        .flat_map(|f| f.get_metric().to_vec())
+        .find(|m| {
+            m.get_label()
+                .iter()
+                .any(|l| l.name() == "user" && l.value() == "exporter_limited")
+        })
+        .map(|m| m.get_gauge().value());
+
+    let _ = sqlx::query("DROP USER IF EXISTS 'exporter_limited'@'%'")
+        .execute(&pool)
+        .await;
+    pool.close().await;
+
+    assert_eq!(limit, Some(7.0));
+    Ok(())
+}
@@ -1,7 +1,9 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::expect_used)]
 use anyhow::Result;
+use mariadb_exporter::exporter::{PoolConfig, WebConfig};
 use secrecy::SecretString;
+use std::collections::BTreeMap;
 
 mod common;
 
@@ -14,10 +16,16 @@ async fn test_exporter_starts_when_db_is_down() -> Result<()> {
 
     let handle = tokio::spawn(async move {
         mariadb_exporter::exporter::new(
-            port,
-            None,
-            dsn,
+            WebConfig {
+                port,
+                ..WebConfig::default()
+            },
+            vec![dsn],
             vec!["default".to_string(), "exporter".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
         )
         .await
     });
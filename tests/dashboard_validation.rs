@@ -2,15 +2,15 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
 
+use mariadb_exporter::collectors::catalog::metric_catalog;
 use regex::Regex;
 use serde_json::Value;
 use std::collections::BTreeSet;
 use std::fs;
-use std::path::{Path, PathBuf};
 
 #[test]
 fn dashboard_metrics_are_exported() -> anyhow::Result<()> {
-    let exported = collect_exported_metrics("src/collectors")?;
+    let exported = collect_exported_metrics()?;
     assert!(
         !exported.is_empty(),
         "should discover exported metrics from collectors"
@@ -76,16 +76,12 @@ fn replication_lag_panel_uses_current_gauge_value() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn collect_exported_metrics(dir: &str) -> anyhow::Result<BTreeSet<String>> {
-    let mut metrics = BTreeSet::new();
-    let pattern = Regex::new(r"mariadb_[a-z0-9_]+").expect("valid regex");
-    for path in walk_files(Path::new(dir))? {
-        let content = fs::read_to_string(&path)?;
-        for mat in pattern.find_iter(&content) {
-            metrics.insert(mat.as_str().trim_matches('"').to_string());
-        }
-    }
-    Ok(metrics)
+/// Every metric `list-metrics` reports, i.e. what the collectors actually register.
+fn collect_exported_metrics() -> anyhow::Result<BTreeSet<String>> {
+    Ok(metric_catalog()?
+        .into_iter()
+        .map(|info| info.name)
+        .collect())
 }
 
 fn collect_dashboard_metrics(path: &str) -> anyhow::Result<BTreeSet<String>> {
@@ -137,24 +133,3 @@ fn find_panel_by_title<'a>(panel: &'a Value, title: &str) -> Option<&'a Value> {
                 .find_map(|child| find_panel_by_title(child, title))
         })
 }
-
-fn walk_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
-    let mut files = Vec::new();
-    let mut stack = vec![dir.to_path_buf()];
-
-    while let Some(path) = stack.pop() {
-        for entry in fs::read_dir(&path)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_dir() {
-                stack.push(path);
-            } else if let Some(ext) = path.extension()
-                && ext == "rs"
-            {
-                files.push(path);
-            }
-        }
-    }
-
-    Ok(files)
-}
@@ -3,7 +3,9 @@
 #![allow(clippy::panic)]
 #![allow(clippy::indexing_slicing)]
 use anyhow::Result;
+use mariadb_exporter::exporter::{PoolConfig, WebConfig};
 use secrecy::SecretString;
+use std::collections::BTreeMap;
 
 mod common;
 
@@ -26,7 +28,19 @@ async fn test_exporter_starts_and_stops() -> Result<()> {
     let dsn = SecretString::from(common::get_test_dsn());
 
     let handle = tokio::spawn(async move {
-        mariadb_exporter::exporter::new(port, None, dsn, vec!["default".to_string()]).await
+        mariadb_exporter::exporter::new(
+            WebConfig {
+                port,
+                ..WebConfig::default()
+            },
+            vec![dsn],
+            vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
+        )
+        .await
     });
 
     assert!(
@@ -50,7 +64,19 @@ async fn test_exporter_metrics_endpoint() -> Result<()> {
     let dsn = SecretString::from(common::get_test_dsn());
 
     let handle = tokio::spawn(async move {
-        mariadb_exporter::exporter::new(port, None, dsn, vec!["default".to_string()]).await
+        mariadb_exporter::exporter::new(
+            WebConfig {
+                port,
+                ..WebConfig::default()
+            },
+            vec![dsn],
+            vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
+        )
+        .await
     });
 
     assert!(
@@ -81,7 +107,19 @@ async fn test_exporter_health_endpoint() -> Result<()> {
     let dsn = SecretString::from(common::get_test_dsn());
 
     let handle = tokio::spawn(async move {
-        mariadb_exporter::exporter::new(port, None, dsn, vec!["default".to_string()]).await
+        mariadb_exporter::exporter::new(
+            WebConfig {
+                port,
+                ..WebConfig::default()
+            },
+            vec![dsn],
+            vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
+        )
+        .await
     });
 
     assert!(
@@ -109,10 +147,17 @@ async fn test_exporter_bind_to_ipv4_localhost() -> Result<()> {
 
     let handle = tokio::spawn(async move {
         mariadb_exporter::exporter::new(
-            port,
-            Some("127.0.0.1".to_string()),
-            dsn,
+            WebConfig {
+                port,
+                listen: Some("127.0.0.1".to_string()),
+                ..WebConfig::default()
+            },
+            vec![dsn],
             vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
         )
         .await
     });
@@ -138,10 +183,17 @@ async fn test_exporter_bind_to_ipv4_all_interfaces() -> Result<()> {
 
     let handle = tokio::spawn(async move {
         mariadb_exporter::exporter::new(
-            port,
-            Some("0.0.0.0".to_string()),
-            dsn,
+            WebConfig {
+                port,
+                listen: Some("0.0.0.0".to_string()),
+                ..WebConfig::default()
+            },
+            vec![dsn],
             vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
         )
         .await
     });
@@ -167,10 +219,17 @@ async fn test_exporter_bind_to_ipv6_localhost() -> Result<()> {
 
     let handle = tokio::spawn(async move {
         mariadb_exporter::exporter::new(
-            port,
-            Some("::1".to_string()),
-            dsn,
+            WebConfig {
+                port,
+                listen: Some("::1".to_string()),
+                ..WebConfig::default()
+            },
+            vec![dsn],
             vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
         )
         .await
     });
@@ -199,7 +258,19 @@ async fn test_exporter_default_bind_auto_detect() -> Result<()> {
 
     // None = auto-detect (try IPv6, fallback to IPv4)
     let handle = tokio::spawn(async move {
-        mariadb_exporter::exporter::new(port, None, dsn, vec!["default".to_string()]).await
+        mariadb_exporter::exporter::new(
+            WebConfig {
+                port,
+                ..WebConfig::default()
+            },
+            vec![dsn],
+            vec!["default".to_string()],
+            BTreeMap::new(),
+            PoolConfig::default(),
+            None,
+            None,
+        )
+        .await
     });
 
     assert!(
@@ -2,6 +2,7 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
 
+use mariadb_exporter::cli::{commands, dispatch::get_enabled_collectors};
 use mariadb_exporter::collectors::util::set_base_connect_options_from_dsn;
 use mariadb_exporter::collectors::{
     COLLECTOR_NAMES, config::CollectorConfig, registry::CollectorRegistry,
@@ -17,6 +18,35 @@ use testcontainers_modules::testcontainers::{
     ImageExt, core::IntoContainerPort, runners::AsyncRunner,
 };
 
+/// Enable every collector the way an orchestrator would, through
+/// `MARIADB_EXPORTER_COLLECTOR_<NAME>=true` only.
+fn collectors_enabled_via_env() -> Vec<String> {
+    let vars: Vec<(String, Option<&str>)> = COLLECTOR_NAMES
+        .iter()
+        .map(|name| {
+            (
+                format!("MARIADB_EXPORTER_COLLECTOR_{}", name.to_ascii_uppercase()),
+                Some("true"),
+            )
+        })
+        .collect();
+
+    temp_env::with_vars(vars, || {
+        get_enabled_collectors(&commands::new().get_matches_from(vec!["mariadb_exporter"]))
+    })
+}
+
+#[test]
+fn collectors_can_be_toggled_via_env() {
+    assert_eq!(collectors_enabled_via_env(), COLLECTOR_NAMES.to_vec());
+
+    let enabled = temp_env::with_var("MARIADB_EXPORTER_NO_COLLECTOR_TLS", Some("true"), || {
+        get_enabled_collectors(&commands::new().get_matches_from(vec!["mariadb_exporter"]))
+    });
+    assert!(!enabled.contains(&"tls".to_string()));
+    assert!(enabled.contains(&"default".to_string()));
+}
+
 #[tokio::test]
 async fn metrics_smoke_includes_optional_collectors() -> anyhow::Result<()> {
     // If CI provides a DSN (service container), use it; otherwise spin up a testcontainer.
@@ -143,12 +173,7 @@ async fn connect_with_candidates_from_dsn(dsn: &str) -> anyhow::Result<sqlx::MyS
 }
 
 async fn run_assertions(pool: sqlx::MySqlPool, extra_needles: &[&str]) -> anyhow::Result<()> {
-    let config = CollectorConfig::new().with_enabled(
-        &COLLECTOR_NAMES
-            .iter()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>(),
-    );
+    let config = CollectorConfig::new().with_enabled(&collectors_enabled_via_env());
     let registry = CollectorRegistry::new(&config);
 
     // First scrape - establishes the count
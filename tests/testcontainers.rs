@@ -627,6 +627,10 @@ async fn assert_primary_collectors(primary_pool: &MySqlPool) -> anyhow::Result<(
         gauge_value(&replication_metrics, "mariadb_primary_binlog_files")? >= 1.0,
         "primary should expose at least one binlog file when log_bin is enabled"
     );
+    assert!(
+        gauge_value(&replication_metrics, "mariadb_primary_binlog_total_bytes")? > 0.0,
+        "primary should expose the binlog size when log_bin is enabled"
+    );
 
     let default_metrics = gather_default_status_metrics(primary_pool).await?;
     assert_gauge_eq(